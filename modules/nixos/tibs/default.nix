@@ -8,10 +8,84 @@
 }:
 with lib; let
   tibs = inputs.self.packages.${system}.tibs;
+  pointerOptions = name: {
+    tapToClick = mkOption {
+      type = types.nullOr types.bool;
+      default = null;
+      description = "Enable tap to click on ${name}s, null keeps the libinput default";
+    };
+    naturalScroll = mkOption {
+      type = types.nullOr types.bool;
+      default = null;
+      description = "Enable natural scrolling on ${name}s";
+    };
+    leftHanded = mkOption {
+      type = types.nullOr types.bool;
+      default = null;
+      description = "Swap the left and right buttons of ${name}s";
+    };
+    accelProfile = mkOption {
+      type = types.nullOr (types.enum ["flat" "adaptive"]);
+      default = null;
+      description = "Pointer acceleration profile of ${name}s";
+    };
+    accelSpeed = mkOption {
+      type = types.nullOr types.float;
+      default = null;
+      description = "Pointer speed of ${name}s, from -1.0 to 1.0";
+    };
+  };
+  pointerEnvironment = prefix: cfg:
+    filterAttrs (_: v: v != null) {
+      "${prefix}_TAP_TO_CLICK" = mapNullable (v: if v then "1" else "0") cfg.tapToClick;
+      "${prefix}_NATURAL_SCROLL" = mapNullable (v: if v then "1" else "0") cfg.naturalScroll;
+      "${prefix}_LEFT_HANDED" = mapNullable (v: if v then "1" else "0") cfg.leftHanded;
+      "${prefix}_ACCEL_PROFILE" = cfg.accelProfile;
+      "${prefix}_ACCEL_SPEED" = mapNullable toString cfg.accelSpeed;
+    };
+  # Only tibs may own its name. Any user may call it, tibs itself only lets the user of
+  # the session on screen switch users
+  dbusPolicy = pkgs.writeTextDir "share/dbus-1/system.d/io.github.coffeeispower.Tibs.conf" ''
+    <!DOCTYPE busconfig PUBLIC "-//freedesktop//DTD D-BUS Bus Configuration 1.0//EN"
+     "http://www.freedesktop.org/standards/dbus/1.0/busconfig.dtd">
+    <busconfig>
+      <policy user="root">
+        <allow own="io.github.coffeeispower.Tibs"/>
+      </policy>
+      <policy context="default">
+        <allow send_destination="io.github.coffeeispower.Tibs"
+               send_interface="io.github.coffeeispower.Tibs.Greeter"/>
+        <allow send_destination="io.github.coffeeispower.Tibs"
+               send_interface="org.freedesktop.DBus.Introspectable"/>
+      </policy>
+    </busconfig>
+  '';
   driversEnv = pkgs.buildEnv {
     name = "graphics-drivers";
     paths = [ config.hardware.graphics.package ] ++ config.hardware.graphics.extraPackages;
   };
+  # Started by the shutdown targets. tibs.service conflicts with shutdown.target, so it's stopped
+  # in the same transaction and the ordering makes it give up the display before this starts
+  shutdownSplash = mode: targets: {
+    description = "Tiago's Incredible Boot Screen (${mode} splash)";
+    wantedBy = targets;
+    after = [ "tibs.service" ];
+    unitConfig.DefaultDependencies = "no";
+    environment = {
+      # logind may already be gone, so take the devices directly
+      TIBS_SEAT_BACKEND = "direct";
+      HOME = "/root";
+      OPENGL_DRIVER_PATH = "${driversEnv}";
+      HYPRCURSOR_THEME = config.tibs.cursorName;
+      XDG_DATA_DIRS = config.tibs.cursorThemesPath;
+      TIBS_ASSETS_FOLDER = "${config.tibs.assetsDir}";
+      LD_LIBRARY_PATH = "${lib.getLib pkgs.libGL}/lib";
+    };
+    serviceConfig = {
+      Type = "simple";
+      ExecStart = "${config.tibs.tibsPath} ${mode}";
+    };
+  };
 in {
   options.tibs = {
     enable = mkOption {
@@ -39,6 +113,40 @@ in {
       default = "catppuccin-frappe-mauve-cursors";
       description = "Name of the cursor to use";
     };
+    seatBackend = mkOption {
+      type = types.enum ["auto" "seatd" "logind" "direct"];
+      default = "auto";
+      description = "How tibs gets access to the display and input devices, every backend except direct goes through libseat";
+    };
+    vtSwitchModifiers = mkOption {
+      type = types.str;
+      default = "Ctrl+Alt";
+      example = "Super+Alt";
+      description = "Modifiers that switch to another VT together with F1 to F12, or \"none\" to disable VT switching";
+    };
+    sessionStopTimeout = mkOption {
+      type = types.ints.unsigned;
+      default = 5;
+      description = "Seconds the processes of a session get to exit on logout before they are killed";
+    };
+    progressIgnoredUnits = mkOption {
+      type = types.listOf types.str;
+      default = [];
+      example = [ "*.timer" "systemd-tmpfiles-clean.service" ];
+      description = "Unit patterns that don't count for the boot progress, `*` and `?` work like in the shell";
+    };
+    bootTimeout = mkOption {
+      type = types.ints.unsigned;
+      default = 300;
+      description = "Seconds to wait for systemd to finish booting before showing the greeter anyway, 0 waits forever";
+    };
+    adminGroup = mkOption {
+      type = types.str;
+      default = "wheel";
+      description = "Group whose members can log in on the boot screen to read the journal of failed units and restart them";
+    };
+    touchpad = pointerOptions "touchpad";
+    mouse = pointerOptions "mouse";
   };
 
   config = mkIf config.tibs.enable {
@@ -51,13 +159,26 @@ in {
     boot.kernelParams = mkForce ["quiet" "loglevel=0" "systemd.show_status=0" "udev.log_level=3" "vt.global_cursor_default=0"];
     systemd.services.tibs = rec {
       description = "Tiago's Incredible Boot Screen";
-      before = [ "display-manager.target" "multi-user.target" "basic.target" ];
+      before = [ "display-manager.target" "multi-user.target" "basic.target" "shutdown.target" ];
       wantedBy = [ "default.target" ];
       unitConfig.DefaultDependencies = "no";
+      # Without the default dependencies nothing stops the greeter on shutdown
+      conflicts = [ "shutdown.target" ];
       requires = ["dbus.service" "dbus-broker.service"];
       after = requires;
+      environment = {
+        TIBS_SEAT_BACKEND = config.tibs.seatBackend;
+        TIBS_VT_SWITCH_MODIFIERS = config.tibs.vtSwitchModifiers;
+        TIBS_SESSION_STOP_TIMEOUT = toString config.tibs.sessionStopTimeout;
+        TIBS_PROGRESS_IGNORE = concatStringsSep "," config.tibs.progressIgnoredUnits;
+        TIBS_ADMIN_GROUP = config.tibs.adminGroup;
+        TIBS_BOOT_TIMEOUT = toString config.tibs.bootTimeout;
+      } // pointerEnvironment "TIBS_TOUCHPAD" config.tibs.touchpad
+        // pointerEnvironment "TIBS_MOUSE" config.tibs.mouse;
       serviceConfig = {
         Type = "simple";
+        # Keeps /var/lib/tibs/boot-history around for estimating the boot progress
+        StateDirectory = "tibs";
         TTYPath="/dev/tty1";
         StandardInput = "tty";
         StandardOutput = "tty";
@@ -81,6 +202,13 @@ in {
         ''; 
       };
     };
+    services.dbus.packages = [ dbusPolicy ];
+    # `tibs switch-user` brings back the greeter from a running session
+    environment.systemPackages = [
+      (pkgs.writeShellScriptBin "tibs" ''exec ${config.tibs.tibsPath} "$@"'')
+    ];
+    systemd.services.tibs-shutdown = shutdownSplash "shutdown" [ "poweroff.target" "halt.target" ];
+    systemd.services.tibs-reboot = shutdownSplash "reboot" [ "reboot.target" "kexec.target" ];
     boot.consoleLogLevel = 0;
     systemd.services.dbus.unitConfig.DefaultDependencies = "no";
     systemd.sockets.dbus.unitConfig.DefaultDependencies = "no";
@@ -23,6 +23,7 @@ fn main() {
 		"gbm",
 		"libunwind",
 		"xkbcommon",
+		"libinput",
 	] {
 		let libs = pkg_config::probe_library(lib).expect(&format!("Failed to find {}", lib));
 		for path in libs.link_paths {
@@ -15,6 +15,8 @@ struct Rustamarine;
 struct Rustamarine *rmarInitialize();
 void *rmarGetProcAddress(struct Rustamarine *self, const char *procName);
 void rmarPollEvents(struct Rustamarine *self);
+// An eventfd in the rmarPollEvents poll set, writing to it makes the poll return
+int rmarGetWakeupFD(struct Rustamarine *self);
 void rmarTearDown(struct Rustamarine *self);
 
 struct RustamarineScreens {
@@ -59,9 +61,84 @@ double rmarGetMouseScrollY(struct Rustamarine* rmar);
 
 const char* rmarGetTypedCharacters(struct Rustamarine* rmar);
 
+enum RustamarineModifier {
+	RMAR_MOD_SHIFT = 1 << 0,
+	RMAR_MOD_CAPS_LOCK = 1 << 1,
+	RMAR_MOD_CTRL = 1 << 2,
+	RMAR_MOD_ALT = 1 << 3,
+	RMAR_MOD_NUM_LOCK = 1 << 4,
+	RMAR_MOD_SUPER = 1 << 5,
+};
+
+uint32_t rmarGetModifiers(struct Rustamarine* rmar);
+void rmarSetLockedModifiers(struct Rustamarine* rmar, uint32_t modifiers, bool active);
+uint32_t rmarKeysymFromName(const char* name);
+size_t rmarKeysymGetName(uint32_t keysym, char* buffer, size_t size);
+
+struct RustamarineKeymapNames {
+	const char *rules;
+	const char *model;
+	const char *layout;
+	const char *variant;
+	const char *options;
+};
+
+bool rmarSetKeymap(struct Rustamarine* rmar, const char* rules, const char* model,
+									 const char* layout, const char* variant, const char* options);
+struct RustamarineKeymapNames rmarGetKeymapNames(struct Rustamarine* rmar);
+uint32_t rmarGetLayoutCount(struct Rustamarine* rmar);
+const char* rmarGetLayoutName(struct Rustamarine* rmar, uint32_t layout);
+uint32_t rmarGetActiveLayout(struct Rustamarine* rmar);
+void rmarSetActiveLayout(struct Rustamarine* rmar, uint32_t layout);
+
+enum RustamarineDeviceClass {
+	RMAR_DEVICE_MOUSE = 0,
+	RMAR_DEVICE_TOUCHPAD = 1,
+};
+
+// Negative values leave the libinput default for that setting untouched
+struct RustamarinePointerConfig {
+	int8_t tapToClick;
+	int8_t naturalScroll;
+	int8_t leftHanded;
+	// 0 = flat, 1 = adaptive
+	int8_t accelProfile;
+	bool setAccelSpeed;
+	// From -1.0 (slowest) to 1.0 (fastest)
+	double accelSpeed;
+};
+
+struct RustamarineTouchPoint {
+	int32_t id;
+	double x;
+	double y;
+	bool justPressed;
+	bool justReleased;
+	// Index of the screen the touchscreen covers, in the order of rmarGetScreens
+	size_t screen;
+};
+
+size_t rmarGetTouchPointCount(struct Rustamarine* rmar);
+bool rmarGetTouchPoint(struct Rustamarine* rmar, size_t index, struct RustamarineTouchPoint* point);
+
+void rmarSetPointerConfig(struct Rustamarine* rmar, enum RustamarineDeviceClass deviceClass,
+													struct RustamarinePointerConfig config);
+
 bool rmarIsDRM(struct Rustamarine* rmar);
+// Name of the seat we acquired devices from, returns the full length like snprintf
+size_t rmarGetSeatName(struct Rustamarine* rmar, char* buffer, size_t size);
 void rmarGoToTTY(struct Rustamarine* rmar, uint16_t tty);
 bool rmarIsOnOriginalTTY(struct Rustamarine* rmar);
+
+enum RustamarineSessionEvent {
+	RMAR_SESSION_EVENT_NONE = 0,
+	// Switched back to our VT, the display and input devices are usable again
+	RMAR_SESSION_EVENT_ACTIVATED = 1,
+	// Switched to another VT, DRM master and input devices were released
+	RMAR_SESSION_EVENT_DEACTIVATED = 2,
+};
+
+enum RustamarineSessionEvent rmarPollSessionEvent(struct Rustamarine* rmar);
 #ifdef __cplusplus
 }
 #endif
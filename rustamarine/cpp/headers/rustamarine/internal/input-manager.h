@@ -8,6 +8,7 @@
 #include <string>
 #include <vector>
 #include <memory>
+#include <optional>
 #include <xkbcommon/xkbcommon-keysyms.h>
 #include <xkbcommon/xkbcommon.h>
 #include <xkbcommon/xkbcommon-compose.h>
@@ -28,6 +29,14 @@ namespace rustamarine {
 			uint64_t stateChangedTimestamp;
 		};
 
+	struct KeymapNames {
+		std::string rules = "evdev";
+		std::string model;
+		std::string layout;
+		std::string variant;
+		std::string options;
+	};
+
 	class InputManager;
 	class Mouse : public std::enable_shared_from_this<Mouse> {
 		public:
@@ -37,9 +46,28 @@ namespace rustamarine {
 		SP<Aquamarine::IPointer> pointer;
 		CHyprSignalListener onRelativeMoveListenerListener, onWarpListener, onScrollListener, onButtonChangeListener, onDisconnectListener;
 		std::map<uint32_t, MouseButtonState> mouseButtonStates;
+		// Apply the libinput settings for this device's class, if any were set
+		void applyConfig();
+		bool isTouchpad();
 		friend InputManager;
 
 	};
+	struct TouchPointState {
+		double x = 0;             // position in screen pixels
+		double y = 0;
+		bool down = false;        // false once the finger is lifted, the point is removed next frame
+		bool justPressed = false;
+		bool justReleased = false;
+		size_t screen = 0;        // index in rmar->screens the position is relative to
+	};
+	class Touch : public std::enable_shared_from_this<Touch> {
+		public:
+		explicit Touch(SP<Aquamarine::ITouch> touch, InputManager* inputManager);
+		InputManager* inputManager;
+		SP<Aquamarine::ITouch> touch;
+		CHyprSignalListener onDownListener, onUpListener, onMoveListener, onCancelListener, onDisconnectListener;
+		friend InputManager;
+	};
 	class Keyboard : public std::enable_shared_from_this<Keyboard> {
 		public:
 		static SP<Keyboard> create(SP<Aquamarine::IKeyboard> keyboard, InputManager* inputManager);
@@ -50,6 +78,10 @@ namespace rustamarine {
 		bool initXkb();
 		// Clean up xkbcommon resources
 		void cleanupXkb();
+		// Switch to another keymap, keeping lock modifiers and the active layout
+		bool useKeymap(struct xkb_keymap* keymap);
+		// Lock the given layout (xkb group)
+		void setLayout(xkb_layout_index_t layout);
 
 		// Convert keysym to UTF-8 string
 		std::string keysymToUtf8(xkb_keysym_t keysym);
@@ -57,6 +89,13 @@ namespace rustamarine {
 		// Handle key event with xkbcommon
 		void handleKeyEvent(xkb_keysym_t keysym);
 
+		// Effective modifiers as a RustamarineModifier mask
+		uint32_t getModifiers();
+		// Lock or unlock the given RustamarineModifier lock bits (caps/num lock)
+		void setLockedModifiers(uint32_t modifiers, bool active);
+		// Sync the keyboard LEDs with the xkb state
+		void updateLeds();
+
 		// xkbcommon state
 		struct xkb_context* xkbContext = nullptr;
 		struct xkb_keymap* xkbKeymap = nullptr;
@@ -78,12 +117,25 @@ namespace rustamarine {
 
 		// Get UTF-8 character string for the current frame
 		void onFrameEnd();
+		// Release every key, button and touch point, e.g. when switching VTs
+		void releaseAll();
+
+		// Keymap shared by all keyboards, compiled on first use
+		struct xkb_keymap* getKeymap();
+		// Compile a new keymap and apply it to every keyboard
+		bool setKeymap(const KeymapNames& names);
+		void setActiveLayout(uint32_t layout);
+		uint32_t getActiveLayout();
 		CHyprSignalListener
 			onNewKeyboardListener,
-			onNewMouseListener;
+			onNewMouseListener,
+			onNewTouchListener;
 		SP<Rustamarine> rmar;
 		std::vector<SP<Mouse>> mouses;
 		std::vector<SP<Keyboard>> keyboards;
+		std::vector<SP<Touch>> touchDevices;
+		// Active touch points by touch id
+		std::map<int32_t, TouchPointState> touchPoints;
 		double mouseDeltaX = 0;
 		double mouseDeltaY = 0;
 		double mouseAbsoluteX = 0;
@@ -94,6 +146,19 @@ namespace rustamarine {
 		// Character input tracking
 		std::string currentFrameUtf8Input;
 
+		struct xkb_context* xkbContext = nullptr;
+		struct xkb_keymap* xkbKeymap = nullptr;
+		KeymapNames keymapNames;
+		uint32_t activeLayout = 0;
+
+		// libinput settings per RustamarineDeviceClass, applied to new devices too
+		std::optional<RustamarinePointerConfig> pointerConfigs[2];
+
+		// Lock modifiers requested through rmarSetLockedModifiers, applied to
+		// keyboards that are plugged in later
+		uint32_t requestedLocksMask = 0;
+		uint32_t requestedLocks = 0;
+
 		friend Mouse;
 		friend Keyboard;
 	};
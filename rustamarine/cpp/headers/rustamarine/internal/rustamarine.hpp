@@ -8,6 +8,7 @@
 #include <rustamarine.h>
 #include <rustamarine/internal/screen.hpp>
 #include <rustamarine/internal/input-manager.h>
+#include <deque>
 #include <vector>
 
 struct RustamarineOpenGLContext {
@@ -19,7 +20,12 @@ struct Rustamarine {
 	std::vector<SP<RustamarineScreen>> screens;
 	struct {
 		Hyprutils::Signal::CHyprSignalListener newOutputListener;
+		Hyprutils::Signal::CHyprSignalListener sessionChangeActiveListener;
 	} listeners;
+	// Session events not yet read through rmarPollSessionEvent
+	std::deque<RustamarineSessionEvent> sessionEvents;
+	// Lets other threads interrupt rmarPollEvents
+	Hyprutils::OS::CFileDescriptor wakeupFD;
 	RustamarineOpenGLContext openGLContext;
 	rustamarine::InputManager inputManager;
 };
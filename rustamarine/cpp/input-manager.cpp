@@ -1,8 +1,10 @@
+#include <algorithm>
 #include <aquamarine/input/Input.hpp>
 #include <chrono>
 #include <cstdio>
 #include <cstring>
 #include <hyprutils/memory/SharedPtr.hpp>
+#include <libinput.h>
 #include <print>
 #include <rustamarine/internal/rustamarine.hpp>
 #include <string>
@@ -15,6 +17,7 @@ using namespace Aquamarine;
 Mouse::Mouse(SP<Aquamarine::IPointer> pointer, InputManager *inputManager)
 		: pointer(pointer), inputManager(inputManager) {
 	std::println("New mouse: {}", pointer->getName());
+	applyConfig();
 	// Listen for relative mouse movement
 	onRelativeMoveListenerListener =
 			pointer->events.move.registerListener([this](std::any event) {
@@ -74,6 +77,99 @@ Mouse::Mouse(SP<Aquamarine::IPointer> pointer, InputManager *inputManager)
 			});
 }
 
+// Implementation of Touch
+// Touchscreens cover the output udev tagged them with (WL_OUTPUT), or the first screen
+static size_t touchScreenIndex(Touch *touch) {
+	auto &screens = touch->inputManager->rmar->screens;
+	auto device = touch->touch->getLibinputHandle();
+	auto outputName = device ? libinput_device_get_output_name(device) : nullptr;
+	if (outputName) {
+		for (size_t i = 0; i < screens.size(); i++)
+			if (screens[i]->output->name == outputName)
+				return i;
+	}
+	return 0;
+}
+
+static TouchPointState touchPositionToScreen(Touch *touch, Hyprutils::Math::Vector2D pos) {
+	auto &screens = touch->inputManager->rmar->screens;
+	auto index = touchScreenIndex(touch);
+	if (index >= screens.size())
+		return {};
+	auto screen = screens[index];
+	return {.x = pos.x * rmarScreenGetWidth(screen.get()),
+					.y = pos.y * rmarScreenGetHeight(screen.get()),
+					.screen = index};
+}
+
+Touch::Touch(SP<Aquamarine::ITouch> touch, InputManager *inputManager)
+		: touch(touch), inputManager(inputManager) {
+	std::println("New touch device: {}", touch->getName());
+	onDownListener = touch->events.down.registerListener([this](std::any d) {
+		auto e = std::any_cast<Aquamarine::ITouch::SDownEvent>(d);
+		auto point = touchPositionToScreen(this, e.pos);
+		point.down = true;
+		point.justPressed = true;
+		this->inputManager->touchPoints[e.touchID] = point;
+	});
+	onMoveListener = touch->events.move.registerListener([this](std::any d) {
+		auto e = std::any_cast<Aquamarine::ITouch::SMotionEvent>(d);
+		auto it = this->inputManager->touchPoints.find(e.touchID);
+		if (it == this->inputManager->touchPoints.end())
+			return;
+		auto point = touchPositionToScreen(this, e.pos);
+		it->second.x = point.x;
+		it->second.y = point.y;
+		it->second.screen = point.screen;
+	});
+	onUpListener = touch->events.up.registerListener([this](std::any d) {
+		auto e = std::any_cast<Aquamarine::ITouch::SUpEvent>(d);
+		auto it = this->inputManager->touchPoints.find(e.touchID);
+		if (it == this->inputManager->touchPoints.end())
+			return;
+		it->second.down = false;
+		it->second.justReleased = true;
+	});
+	onCancelListener = touch->events.cancel.registerListener([this](std::any d) {
+		auto e = std::any_cast<Aquamarine::ITouch::SCancelEvent>(d);
+		this->inputManager->touchPoints.erase(e.touchID);
+	});
+	onDisconnectListener = touch->events.destroy.registerListener([this](std::any) {
+		auto touchPtr = this->touch.get();
+		std::erase_if(this->inputManager->touchDevices,
+									[touchPtr](const auto &t) { return t->touch.get() == touchPtr; });
+	});
+}
+
+bool Mouse::isTouchpad() {
+	auto device = pointer->getLibinputHandle();
+	return device && libinput_device_config_tap_get_finger_count(device) > 0;
+}
+
+void Mouse::applyConfig() {
+	auto device = pointer->getLibinputHandle();
+	if (!device)
+		return;
+	auto &config = inputManager->pointerConfigs[isTouchpad() ? RMAR_DEVICE_TOUCHPAD : RMAR_DEVICE_MOUSE];
+	if (!config)
+		return;
+	if (config->tapToClick >= 0 && libinput_device_config_tap_get_finger_count(device) > 0)
+		libinput_device_config_tap_set_enabled(
+				device, config->tapToClick ? LIBINPUT_CONFIG_TAP_ENABLED : LIBINPUT_CONFIG_TAP_DISABLED);
+	if (config->naturalScroll >= 0 && libinput_device_config_scroll_has_natural_scroll(device))
+		libinput_device_config_scroll_set_natural_scroll_enabled(device, config->naturalScroll);
+	if (config->leftHanded >= 0 && libinput_device_config_left_handed_is_available(device))
+		libinput_device_config_left_handed_set(device, config->leftHanded);
+	if (libinput_device_config_accel_is_available(device)) {
+		if (config->accelProfile >= 0)
+			libinput_device_config_accel_set_profile(device, config->accelProfile == 0
+																													 ? LIBINPUT_CONFIG_ACCEL_PROFILE_FLAT
+																													 : LIBINPUT_CONFIG_ACCEL_PROFILE_ADAPTIVE);
+		if (config->setAccelSpeed)
+			libinput_device_config_accel_set_speed(device, std::clamp(config->accelSpeed, -1.0, 1.0));
+	}
+}
+
 // Implementation of Keyboard
 Keyboard::Keyboard(SP<Aquamarine::IKeyboard> keyboard,
 									 InputManager *inputManager)
@@ -84,6 +180,10 @@ Keyboard::Keyboard(SP<Aquamarine::IKeyboard> keyboard,
 		fprintf(stderr, "Failed to initialize XKB for keyboard\n");
 		return;
 	}
+	if (inputManager->requestedLocksMask) {
+		setLockedModifiers(inputManager->requestedLocksMask & inputManager->requestedLocks, true);
+		setLockedModifiers(inputManager->requestedLocksMask & ~inputManager->requestedLocks, false);
+	}
 
 	// Listen for key updates
 	this->onKeyUpdateListener =
@@ -98,8 +198,10 @@ Keyboard::Keyboard(SP<Aquamarine::IKeyboard> keyboard,
 				auto timeMs = keyEvent.timeMs;
 
 				// Update xkb state for modifiers and other state tracking
-				xkb_state_update_key(xkbState, evdev_keycode,
-														 pressed ? XKB_KEY_DOWN : XKB_KEY_UP);
+				auto changed = xkb_state_update_key(
+						xkbState, evdev_keycode, pressed ? XKB_KEY_DOWN : XKB_KEY_UP);
+				if (changed & XKB_STATE_LEDS)
+					updateLeds();
 
 				// Get proper keysym from xkb state
 				xkb_keysym_t keysym =
@@ -137,18 +239,8 @@ bool Keyboard::initXkb() {
 	if (!xkbContext) {
 		return false;
 	}
-	struct xkb_rule_names rules = {.rules = "evdev", .model = "", .layout = getenv("RUSTAMARINE_KB_LAYOUT")};
-
-	xkbKeymap = xkb_keymap_new_from_names(xkbContext, &rules,
-																				XKB_KEYMAP_COMPILE_NO_FLAGS);
-
-	if (!xkbKeymap) {
-		cleanupXkb();
-		return false;
-	}
-
-	xkbState = xkb_state_new(xkbKeymap);
-	if (!xkbState) {
+	auto keymap = inputManager->getKeymap();
+	if (!keymap || !useKeymap(keymap)) {
 		cleanupXkb();
 		return false;
 	}
@@ -164,6 +256,36 @@ bool Keyboard::initXkb() {
 	return true;
 }
 
+bool Keyboard::useKeymap(struct xkb_keymap *keymap) {
+	auto state = xkb_state_new(keymap);
+	if (!state)
+		return false;
+	// Keep caps/num lock across keymap changes
+	uint32_t locks = getModifiers() & (RMAR_MOD_CAPS_LOCK | RMAR_MOD_NUM_LOCK);
+	if (xkbState)
+		xkb_state_unref(xkbState);
+	if (xkbKeymap)
+		xkb_keymap_unref(xkbKeymap);
+	xkbKeymap = xkb_keymap_ref(keymap);
+	xkbState = state;
+	// Keys held while the keymap changes may now map to other keysyms
+	keystates.clear();
+	if (locks)
+		setLockedModifiers(locks, true);
+	setLayout(inputManager->activeLayout);
+	return true;
+}
+
+void Keyboard::setLayout(xkb_layout_index_t layout) {
+	if (!xkbState || layout >= xkb_keymap_num_layouts(xkbKeymap))
+		return;
+	auto depressed = xkb_state_serialize_mods(xkbState, XKB_STATE_MODS_DEPRESSED);
+	auto latched = xkb_state_serialize_mods(xkbState, XKB_STATE_MODS_LATCHED);
+	auto locked = xkb_state_serialize_mods(xkbState, XKB_STATE_MODS_LOCKED);
+	xkb_state_update_mask(xkbState, depressed, latched, locked, 0, 0, layout);
+	updateLeds();
+}
+
 void Keyboard::cleanupXkb() {
 	if (xkbComposeState) {
 		xkb_compose_state_unref(xkbComposeState);
@@ -263,8 +385,66 @@ void Keyboard::handleKeyEvent(xkb_keysym_t keysym) {
 	inputManager->currentFrameUtf8Input = inputManager->currentFrameUtf8Input + buffer;
 }
 
+uint32_t Keyboard::getModifiers() {
+	if (!xkbState)
+		return 0;
+	static const std::pair<const char *, uint32_t> modifierNames[] = {
+			{XKB_MOD_NAME_SHIFT, RMAR_MOD_SHIFT}, {XKB_MOD_NAME_CAPS, RMAR_MOD_CAPS_LOCK},
+			{XKB_MOD_NAME_CTRL, RMAR_MOD_CTRL},   {XKB_MOD_NAME_ALT, RMAR_MOD_ALT},
+			{XKB_MOD_NAME_NUM, RMAR_MOD_NUM_LOCK}, {XKB_MOD_NAME_LOGO, RMAR_MOD_SUPER},
+	};
+	uint32_t modifiers = 0;
+	for (auto &[name, bit] : modifierNames) {
+		if (xkb_state_mod_name_is_active(xkbState, name, XKB_STATE_MODS_EFFECTIVE) == 1)
+			modifiers |= bit;
+	}
+	return modifiers;
+}
+
+void Keyboard::setLockedModifiers(uint32_t modifiers, bool active) {
+	if (!xkbState)
+		return;
+	static const std::pair<const char *, uint32_t> lockNames[] = {
+			{XKB_MOD_NAME_CAPS, RMAR_MOD_CAPS_LOCK},
+			{XKB_MOD_NAME_NUM, RMAR_MOD_NUM_LOCK},
+	};
+	auto depressed = xkb_state_serialize_mods(xkbState, XKB_STATE_MODS_DEPRESSED);
+	auto latched = xkb_state_serialize_mods(xkbState, XKB_STATE_MODS_LATCHED);
+	auto locked = xkb_state_serialize_mods(xkbState, XKB_STATE_MODS_LOCKED);
+	auto group = xkb_state_serialize_layout(xkbState, XKB_STATE_LAYOUT_EFFECTIVE);
+	for (auto &[name, bit] : lockNames) {
+		if (!(modifiers & bit))
+			continue;
+		auto index = xkb_keymap_mod_get_index(xkbKeymap, name);
+		if (index == XKB_MOD_INVALID)
+			continue;
+		if (active)
+			locked |= 1u << index;
+		else
+			locked &= ~(1u << index);
+	}
+	xkb_state_update_mask(xkbState, depressed, latched, locked, 0, 0, group);
+	updateLeds();
+}
+
+void Keyboard::updateLeds() {
+	if (!xkbState)
+		return;
+	// Same bit layout as libinput's enum libinput_led
+	uint32_t leds = 0;
+	if (xkb_state_led_name_is_active(xkbState, XKB_LED_NAME_NUM) == 1)
+		leds |= 1 << 0;
+	if (xkb_state_led_name_is_active(xkbState, XKB_LED_NAME_CAPS) == 1)
+		leds |= 1 << 1;
+	if (xkb_state_led_name_is_active(xkbState, XKB_LED_NAME_SCROLL) == 1)
+		leds |= 1 << 2;
+	keyboard->updateLEDs(leds);
+}
+
 // Implementation of InputManager
 InputManager::InputManager(SP<Rustamarine> rmar) : rmar(rmar) {
+	if (auto layout = getenv("RUSTAMARINE_KB_LAYOUT"))
+		keymapNames.layout = layout;
 	// Listen for new keyboards
 	onNewKeyboardListener = rmar->backend->events.newKeyboard.registerListener(
 			[rmar](std::any event) {
@@ -273,6 +453,13 @@ InputManager::InputManager(SP<Rustamarine> rmar) : rmar(rmar) {
 						Hyprutils::Memory::makeShared<Keyboard>(keyboard,
 																										&rmar->inputManager));
 			});
+	// Listen for new touchscreens
+	onNewTouchListener =
+			rmar->backend->events.newTouch.registerListener([rmar](std::any event) {
+				auto touch = std::any_cast<SP<Aquamarine::ITouch>>(event);
+				rmar->inputManager.touchDevices.emplace_back(
+						Hyprutils::Memory::makeShared<Touch>(touch, &rmar->inputManager));
+			});
 	// Listen for new mice
 	onNewMouseListener =
 			rmar->backend->events.newPointer.registerListener([rmar](std::any event) {
@@ -282,6 +469,65 @@ InputManager::InputManager(SP<Rustamarine> rmar) : rmar(rmar) {
 			});
 }
 
+static const char *emptyToNull(const std::string &s) {
+	return s.empty() ? nullptr : s.c_str();
+}
+
+struct xkb_keymap *InputManager::getKeymap() {
+	if (!xkbKeymap && !setKeymap(keymapNames))
+		return nullptr;
+	return xkbKeymap;
+}
+
+bool InputManager::setKeymap(const KeymapNames &names) {
+	if (!xkbContext)
+		xkbContext = xkb_context_new(XKB_CONTEXT_NO_FLAGS);
+	if (!xkbContext)
+		return false;
+	struct xkb_rule_names rules = {
+			.rules = emptyToNull(names.rules),
+			.model = emptyToNull(names.model),
+			.layout = emptyToNull(names.layout),
+			.variant = emptyToNull(names.variant),
+			.options = emptyToNull(names.options),
+	};
+	auto keymap = xkb_keymap_new_from_names(xkbContext, &rules, XKB_KEYMAP_COMPILE_NO_FLAGS);
+	if (!keymap) {
+		std::println("Failed to compile keymap (rules={}, model={}, layout={}, variant={}, options={})",
+								 names.rules, names.model, names.layout, names.variant, names.options);
+		return false;
+	}
+	if (xkbKeymap)
+		xkb_keymap_unref(xkbKeymap);
+	xkbKeymap = keymap;
+	keymapNames = names;
+	if (activeLayout >= xkb_keymap_num_layouts(keymap))
+		activeLayout = 0;
+	for (auto &kb : keyboards) {
+		if (kb->xkbContext)
+			kb->useKeymap(keymap);
+	}
+	return true;
+}
+
+void InputManager::setActiveLayout(uint32_t layout) {
+	auto keymap = getKeymap();
+	if (!keymap || layout >= xkb_keymap_num_layouts(keymap))
+		return;
+	activeLayout = layout;
+	for (auto &kb : keyboards)
+		kb->setLayout(layout);
+}
+
+uint32_t InputManager::getActiveLayout() {
+	// Group switching keys (e.g. grp:alt_shift_toggle) change the layout
+	// per keyboard, so report the one that was used last
+	for (auto &kb : keyboards) {
+		if (kb->xkbState)
+			activeLayout = xkb_state_serialize_layout(kb->xkbState, XKB_STATE_LAYOUT_EFFECTIVE);
+	}
+	return activeLayout;
+}
 
 // xkbcommon modifier names
 #define XKB_MOD_NAME_SHIFT "Shift"
@@ -355,6 +601,35 @@ void InputManager::onFrameEnd() {
 		for (auto &[_, state] : mouse->mouseButtonStates) {
 			state.justChanged = false;
 		}
+	// Lifted fingers are reported for one frame after the up event
+	std::erase_if(touchPoints, [](const auto &point) { return !point.second.down; });
+	for (auto &[_, point] : touchPoints)
+		point.justPressed = false;
+}
+void InputManager::releaseAll() {
+	for (auto &kb : this->keyboards) {
+		for (auto &[_, state] : kb->keystates) {
+			state.justChanged = state.down;
+			state.down = false;
+			state.repeating = false;
+			state.shouldTypeChar = false;
+		}
+		if (kb->xkbState) {
+			// Drop depressed modifiers but keep locks and the layout
+			auto locked = xkb_state_serialize_mods(kb->xkbState, XKB_STATE_MODS_LOCKED);
+			auto layout = xkb_state_serialize_layout(kb->xkbState, XKB_STATE_LAYOUT_EFFECTIVE);
+			xkb_state_update_mask(kb->xkbState, 0, 0, locked, 0, 0, layout);
+		}
+	}
+	for (auto &mouse : this->mouses)
+		for (auto &[_, state] : mouse->mouseButtonStates) {
+			state.justChanged = state.down;
+			state.down = false;
+		}
+	for (auto &[_, point] : touchPoints) {
+		point.justReleased = point.down;
+		point.down = false;
+	}
 }
 bool rmarIsKeyDown(Rustamarine *rmar, uint32_t key) {
   if (!rmar)
@@ -488,3 +763,120 @@ void rmarSetMouseY(Rustamarine *rmar, int y) {
 	rmar->inputManager.mouseAbsoluteY = static_cast<double>(y);
 	rmar->inputManager.mouseDeltaY += rmar->inputManager.mouseAbsoluteY - prev;
 }
+
+uint32_t rmarGetModifiers(Rustamarine *rmar) {
+	if (!rmar)
+		return 0;
+	uint32_t modifiers = 0;
+	for (auto &kb : rmar->inputManager.keyboards)
+		modifiers |= kb->getModifiers();
+	return modifiers;
+}
+
+void rmarSetLockedModifiers(Rustamarine *rmar, uint32_t modifiers, bool active) {
+	if (!rmar)
+		return;
+	modifiers &= RMAR_MOD_CAPS_LOCK | RMAR_MOD_NUM_LOCK;
+	auto &inputManager = rmar->inputManager;
+	inputManager.requestedLocksMask |= modifiers;
+	if (active)
+		inputManager.requestedLocks |= modifiers;
+	else
+		inputManager.requestedLocks &= ~modifiers;
+	for (auto &kb : inputManager.keyboards)
+		kb->setLockedModifiers(modifiers, active);
+}
+
+uint32_t rmarKeysymFromName(const char *name) {
+	auto keysym = xkb_keysym_from_name(name, XKB_KEYSYM_NO_FLAGS);
+	if (keysym == XKB_KEY_NoSymbol)
+		keysym = xkb_keysym_from_name(name, XKB_KEYSYM_CASE_INSENSITIVE);
+	return keysym;
+}
+
+size_t rmarKeysymGetName(uint32_t keysym, char *buffer, size_t size) {
+	int len = xkb_keysym_get_name(keysym, buffer, size);
+	return len < 0 ? 0 : static_cast<size_t>(len);
+}
+
+bool rmarSetKeymap(Rustamarine *rmar, const char *rules, const char *model,
+									 const char *layout, const char *variant, const char *options) {
+	if (!rmar)
+		return false;
+	return rmar->inputManager.setKeymap({
+			.rules = rules ? rules : "",
+			.model = model ? model : "",
+			.layout = layout ? layout : "",
+			.variant = variant ? variant : "",
+			.options = options ? options : "",
+	});
+}
+
+struct RustamarineKeymapNames rmarGetKeymapNames(Rustamarine *rmar) {
+	auto &names = rmar->inputManager.keymapNames;
+	return {
+			.rules = names.rules.c_str(),
+			.model = names.model.c_str(),
+			.layout = names.layout.c_str(),
+			.variant = names.variant.c_str(),
+			.options = names.options.c_str(),
+	};
+}
+
+uint32_t rmarGetLayoutCount(Rustamarine *rmar) {
+	if (!rmar)
+		return 0;
+	auto keymap = rmar->inputManager.getKeymap();
+	return keymap ? xkb_keymap_num_layouts(keymap) : 0;
+}
+
+const char *rmarGetLayoutName(Rustamarine *rmar, uint32_t layout) {
+	if (!rmar)
+		return nullptr;
+	auto keymap = rmar->inputManager.getKeymap();
+	if (!keymap || layout >= xkb_keymap_num_layouts(keymap))
+		return nullptr;
+	return xkb_keymap_layout_get_name(keymap, layout);
+}
+
+uint32_t rmarGetActiveLayout(Rustamarine *rmar) {
+	if (!rmar)
+		return 0;
+	return rmar->inputManager.getActiveLayout();
+}
+
+void rmarSetActiveLayout(Rustamarine *rmar, uint32_t layout) {
+	if (!rmar)
+		return;
+	rmar->inputManager.setActiveLayout(layout);
+}
+
+void rmarSetPointerConfig(Rustamarine *rmar, enum RustamarineDeviceClass deviceClass,
+													struct RustamarinePointerConfig config) {
+	if (!rmar || deviceClass > RMAR_DEVICE_TOUCHPAD)
+		return;
+	rmar->inputManager.pointerConfigs[deviceClass] = config;
+	for (auto &mouse : rmar->inputManager.mouses)
+		mouse->applyConfig();
+}
+
+size_t rmarGetTouchPointCount(Rustamarine *rmar) {
+	if (!rmar)
+		return 0;
+	return rmar->inputManager.touchPoints.size();
+}
+
+bool rmarGetTouchPoint(Rustamarine *rmar, size_t index, struct RustamarineTouchPoint *point) {
+	if (!rmar || index >= rmar->inputManager.touchPoints.size())
+		return false;
+	auto it = std::next(rmar->inputManager.touchPoints.begin(), index);
+	*point = {
+			.id = it->first,
+			.x = it->second.x,
+			.y = it->second.y,
+			.justPressed = it->second.justPressed,
+			.justReleased = it->second.justReleased,
+			.screen = it->second.screen,
+	};
+	return true;
+}
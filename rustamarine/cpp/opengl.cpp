@@ -5,6 +5,7 @@
 #include <EGL/eglext.h>
 #include <GLES3/gl32.h>
 #include <cstring>
+#include <fcntl.h>
 #include <gbm.h>
 #include <hyprutils/os/FileDescriptor.hpp>
 #include <rustamarine/internal/opengl.hpp>
@@ -65,6 +66,7 @@ EGLDeviceEXT eglDeviceFromDRMFD(int drmFD) {
 }
 static int openRenderNode(int drmFd) {
 	auto renderName = drmGetRenderDeviceNameFromFd(drmFd);
+	bool isPrimaryNode = !renderName;
 	if (!renderName) {
 		// This can happen on split render/display platforms, fallback to
 		// primary node
@@ -91,6 +93,12 @@ static int openRenderNode(int drmFd) {
 	printf("[LOG] openRenderNode got drm device %s\n", renderName);
 
 	int renderFD = open(renderName, O_RDWR | O_CLOEXEC);
+	if (renderFD < 0 && isPrimaryNode) {
+		// Unprivileged users can't open the primary node, reuse the fd the seat
+		// gave us
+		printf("[LOG] openRenderNode reusing the seat's drm fd\n");
+		renderFD = fcntl(drmFd, F_DUPFD_CLOEXEC, 0);
+	}
 	if (renderFD < 0)
 		printf("[ERR] openRenderNode failed to open drm device %s\n", renderName);
 
@@ -6,6 +6,8 @@
 #include <hyprutils/memory/SharedPtr.hpp>
 #include <iostream>
 #include <poll.h>
+#include <sys/eventfd.h>
+#include <unistd.h>
 #include <rustamarine/internal/opengl.hpp>
 #include <rustamarine/internal/rustamarine.hpp>
 
@@ -48,6 +50,27 @@ void setupEventListeners(SP<Rustamarine> rmar) {
 
 }
 
+// The session only exists once the backend is started
+void setupSessionListeners(SP<Rustamarine> rmar) {
+	if (!rmar->backend->hasSession())
+		return;
+	rmar->listeners.sessionChangeActiveListener =
+			rmar->backend->session->events.changeActive.registerListener(
+					[rmar](std::any _) {
+						if (!rmar->backend->session->active) {
+							// Keys held while switching away never get a release event
+							rmar->inputManager.releaseAll();
+							rmar->sessionEvents.push_back(RMAR_SESSION_EVENT_DEACTIVATED);
+							return;
+						}
+						rmar->sessionEvents.push_back(RMAR_SESSION_EVENT_ACTIVATED);
+						// Whatever was on screen belongs to the previous VT owner
+						for (auto &screen : rmar->screens) {
+							screen->output->scheduleFrame(Aquamarine::IOutput::AQ_SCHEDULE_DAMAGE);
+						}
+					});
+}
+
 Rustamarine *rmarInitialize() {
 	setup_segfault_handler();
 	Aquamarine::SBackendOptions options;
@@ -57,9 +80,13 @@ Rustamarine *rmarInitialize() {
 	SP<Rustamarine> rmar(
 			new Rustamarine{.backend = aqBackend, .screens = {}, .listeners = {}});
 	setupEventListeners(rmar);
+	rmar->wakeupFD = Hyprutils::OS::CFileDescriptor{eventfd(0, EFD_CLOEXEC | EFD_NONBLOCK)};
+	if (!rmar->wakeupFD.isValid())
+		panic("Failed to create the wakeup eventfd");
 	rmar->inputManager = rustamarine::InputManager(rmar);
 	if (!rmar->backend->start())
 		panic("Failed to start aquamarine backend");
+	setupSessionListeners(rmar);
 	initializeOpenGL(rmar);
 
 	rmar.impl_->inc();
@@ -77,11 +104,18 @@ void rmarPollEvents(struct Rustamarine *self) {
 	for (const auto &pfd : pollFDs) {
 		fds.push_back({pfd->fd, POLLIN, 0});
 	}
+	fds.push_back({self->wakeupFD.get(), POLLIN, 0});
 	int ret = poll(fds.data(), fds.size(), -1);
 	if (ret > 0) {
 		for (size_t i = 0; i < fds.size(); ++i) {
 			if (fds[i].revents & POLLIN) {
 				auto fd = fds[i].fd;
+				if (fd == self->wakeupFD.get()) {
+					// Reset the counter, so the next poll blocks again
+					uint64_t wakeups;
+					[[maybe_unused]] auto _ = read(fd, &wakeups, sizeof(wakeups));
+					continue;
+				}
 				auto it = std::find_if(pollFDs.begin(), pollFDs.end(),
 															 [fd](const auto &pfd) { return pfd->fd == fd; });
 				if (it == pollFDs.end())
@@ -91,6 +125,9 @@ void rmarPollEvents(struct Rustamarine *self) {
 		}
 	}
 }
+int rmarGetWakeupFD(struct Rustamarine *self) {
+	return self->wakeupFD.get();
+}
 void rmarTearDown(struct Rustamarine *self) {
 	tearDownOpenGL(&self->openGLContext);
 	delete self;
@@ -118,10 +155,31 @@ bool rmarIsDRM(struct Rustamarine* rmar) {
 	return rmar->backend->hasSession();
 }
 
+size_t rmarGetSeatName(struct Rustamarine *rmar, char *buffer, size_t size) {
+	if (!rmarIsDRM(rmar))
+		return 0;
+	const auto &name = rmar->backend->session->seatName;
+	if (size > 0) {
+		auto copied = std::min(name.size(), size - 1);
+		memcpy(buffer, name.data(), copied);
+		buffer[copied] = '\0';
+	}
+	return name.size();
+}
+
 void rmarGoToTTY(struct Rustamarine *rmar, uint16_t tty) {
+	if (!rmarIsDRM(rmar))
+		return;
 	rmar->backend->session->switchVT(tty);
 }
 bool rmarIsOnOriginalTTY(struct Rustamarine* rmar) {
 	if(!rmarIsDRM(rmar)) return true;
 	return rmar->backend->session->active;
 }
+enum RustamarineSessionEvent rmarPollSessionEvent(struct Rustamarine *rmar) {
+	if (rmar->sessionEvents.empty())
+		return RMAR_SESSION_EVENT_NONE;
+	auto event = rmar->sessionEvents.front();
+	rmar->sessionEvents.pop_front();
+	return event;
+}
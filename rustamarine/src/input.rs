@@ -1,4 +1,12 @@
 pub mod keys;
+mod keymap;
+mod keysym;
+mod pointer_config;
+mod touch;
+pub use keymap::*;
+pub use keysym::*;
+pub use pointer_config::*;
+pub use touch::*;
 use super::*;
 impl Rustamarine {
 	/// Check if a key is currently being held down.
@@ -10,8 +18,8 @@ impl Rustamarine {
 	/// # Returns
 	///
 	/// `true` if the key is currently down, `false` otherwise
-	pub fn is_key_down(&self, key: u32) -> bool {
-		unsafe { sys::rmarIsKeyDown(self.inner, key) }
+	pub fn is_key_down(&self, key: Keysym) -> bool {
+		unsafe { sys::rmarIsKeyDown(self.inner, key.0) }
 	}
 
 	/// Check if a key was just pressed this frame.
@@ -25,8 +33,8 @@ impl Rustamarine {
 	/// # Returns
 	///
 	/// `true` if the key was just pressed, `false` otherwise
-	pub fn is_key_pressed(&self, key: u32) -> bool {
-		unsafe { sys::rmarIsKeyPressed(self.inner, key) }
+	pub fn is_key_pressed(&self, key: Keysym) -> bool {
+		unsafe { sys::rmarIsKeyPressed(self.inner, key.0) }
 	}
 
 	/// Check if a key was just released this frame.
@@ -40,8 +48,8 @@ impl Rustamarine {
 	/// # Returns
 	///
 	/// `true` if the key was just released, `false` otherwise
-	pub fn is_key_released(&self, key: u32) -> bool {
-		unsafe { sys::rmarIsKeyReleased(self.inner, key) }
+	pub fn is_key_released(&self, key: Keysym) -> bool {
+		unsafe { sys::rmarIsKeyReleased(self.inner, key.0) }
 	}
 
 	/// Check if a key should generate a character in text input.
@@ -56,8 +64,8 @@ impl Rustamarine {
 	/// # Returns
 	///
 	/// `true` if the key should generate a character, `false` otherwise
-	pub fn should_type_key(&self, key: u32) -> bool {
-		unsafe { sys::rmarShouldTypeKey(self.inner, key) }
+	pub fn should_type_key(&self, key: Keysym) -> bool {
+		unsafe { sys::rmarShouldTypeKey(self.inner, key.0) }
 	}
 	/// Check if a mouse button is currently being held down.
 	///
@@ -172,6 +180,58 @@ impl Rustamarine {
 	pub fn set_mouse_y(&self, y: i32) {
 		unsafe { sys::rmarSetMouseY(self.inner, y) }
 	}
+	/// Get the keyboard modifiers that are currently active.
+	///
+	/// When more than one keyboard is connected, the modifiers of all of them are combined.
+	///
+	/// # Returns
+	///
+	/// The active [`Modifiers`], including lock modifiers like Caps Lock and Num Lock
+	pub fn get_modifiers(&self) -> Modifiers {
+		Modifiers::from_bits_truncate(unsafe { sys::rmarGetModifiers(self.inner) })
+	}
+
+	/// Check if Caps Lock is on.
+	pub fn is_caps_lock_active(&self) -> bool {
+		self.get_modifiers().contains(Modifiers::CAPS_LOCK)
+	}
+
+	/// Check if Num Lock is on.
+	pub fn is_num_lock_active(&self) -> bool {
+		self.get_modifiers().contains(Modifiers::NUM_LOCK)
+	}
+
+	/// Turn lock modifiers on or off, updating the keyboard LEDs.
+	///
+	/// The requested state is also applied to keyboards that are connected later,
+	/// so this can be called at startup before any keyboard shows up.
+	///
+	/// # Arguments
+	///
+	/// * `modifiers` - The locks to change, only [`Modifiers::LOCKS`] are taken into account
+	/// * `active` - Whether the locks should be turned on or off
+	pub fn set_locked_modifiers(&self, modifiers: Modifiers, active: bool) {
+		unsafe { sys::rmarSetLockedModifiers(self.inner, modifiers.bits(), active) }
+	}
+
+	/// Turn Caps Lock on or off.
+	pub fn set_caps_lock(&self, active: bool) {
+		self.set_locked_modifiers(Modifiers::CAPS_LOCK, active);
+	}
+
+	/// Turn Num Lock on or off.
+	pub fn set_num_lock(&self, active: bool) {
+		self.set_locked_modifiers(Modifiers::NUM_LOCK, active);
+	}
+
+	/// Check if a shortcut was just pressed this frame.
+	///
+	/// The key must have just been pressed while exactly the shortcut's modifiers are held,
+	/// ignoring lock modifiers.
+	pub fn is_shortcut_pressed(&self, shortcut: &Shortcut) -> bool {
+		self.is_key_pressed(shortcut.keysym) && shortcut.matches_modifiers(self.get_modifiers())
+	}
+
 	/// Get the UTF-8 text input for the current frame.
 	///
 	/// This returns all the characters typed since the last frame, taking into account
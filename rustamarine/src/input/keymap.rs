@@ -0,0 +1,117 @@
+use std::ffi::{CStr, CString};
+
+use crate::{sys, Rustamarine};
+
+/// The xkb RMLVO names (rules, model, layout, variant, options) used to compile the keymap.
+///
+/// Empty fields fall back to the xkbcommon defaults (or the `XKB_DEFAULT_*` environment
+/// variables). `layout`, `variant` and `options` are comma separated lists, where each layout
+/// is one xkb group that can be switched to with [`Rustamarine::set_active_layout`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeymapNames {
+	pub rules: String,
+	pub model: String,
+	pub layout: String,
+	pub variant: String,
+	pub options: String,
+}
+
+impl KeymapNames {
+	/// Get the short name of every layout (e.g. `["us", "pt"]`).
+	pub fn layouts(&self) -> Vec<&str> {
+		self
+			.layout
+			.split(',')
+			.map(str::trim)
+			.filter(|l| !l.is_empty())
+			.collect()
+	}
+}
+
+impl Rustamarine {
+	/// Compile a new keymap and use it on every keyboard, including the ones connected later.
+	///
+	/// The `RUSTAMARINE_KB_LAYOUT` environment variable is only used for the initial keymap.
+	///
+	/// # Returns
+	///
+	/// `false` if the keymap failed to compile, in which case the previous keymap is kept
+	pub fn set_keymap(&self, names: &KeymapNames) -> bool {
+		let to_cstring = |s: &str| CString::new(s).unwrap_or_default();
+		let rules = to_cstring(&names.rules);
+		let model = to_cstring(&names.model);
+		let layout = to_cstring(&names.layout);
+		let variant = to_cstring(&names.variant);
+		let options = to_cstring(&names.options);
+		unsafe {
+			sys::rmarSetKeymap(
+				self.inner,
+				rules.as_ptr(),
+				model.as_ptr(),
+				layout.as_ptr(),
+				variant.as_ptr(),
+				options.as_ptr(),
+			)
+		}
+	}
+
+	/// Get the names the current keymap was compiled from.
+	pub fn get_keymap(&self) -> KeymapNames {
+		let to_string = |ptr: *const std::ffi::c_char| {
+			if ptr.is_null() {
+				return String::new();
+			}
+			unsafe { CStr::from_ptr(ptr) }
+				.to_string_lossy()
+				.into_owned()
+		};
+		let names = unsafe { sys::rmarGetKeymapNames(self.inner) };
+		KeymapNames {
+			rules: to_string(names.rules),
+			model: to_string(names.model),
+			layout: to_string(names.layout),
+			variant: to_string(names.variant),
+			options: to_string(names.options),
+		}
+	}
+
+	/// Get how many layouts (xkb groups) the current keymap has.
+	pub fn get_layout_count(&self) -> u32 {
+		unsafe { sys::rmarGetLayoutCount(self.inner) }
+	}
+
+	/// Get the human readable name of a layout (e.g. `"English (US)"`).
+	///
+	/// # Returns
+	///
+	/// `None` if `layout` is out of range
+	pub fn get_layout_name(&self, layout: u32) -> Option<String> {
+		let ptr = unsafe { sys::rmarGetLayoutName(self.inner, layout) };
+		if ptr.is_null() {
+			return None;
+		}
+		Some(
+			unsafe { CStr::from_ptr(ptr) }
+				.to_string_lossy()
+				.into_owned(),
+		)
+	}
+
+	/// Get the index of the layout that is currently in use.
+	pub fn get_active_layout(&self) -> u32 {
+		unsafe { sys::rmarGetActiveLayout(self.inner) }
+	}
+
+	/// Switch every keyboard to another layout. Out of range indices are ignored.
+	pub fn set_active_layout(&self, layout: u32) {
+		unsafe { sys::rmarSetActiveLayout(self.inner, layout) }
+	}
+
+	/// Switch to the next layout, wrapping around after the last one.
+	pub fn next_layout(&self) {
+		let count = self.get_layout_count();
+		if count > 1 {
+			self.set_active_layout((self.get_active_layout() + 1) % count);
+		}
+	}
+}
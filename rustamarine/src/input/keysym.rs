@@ -0,0 +1,286 @@
+use std::{
+	ffi::{c_char, CString},
+	fmt,
+	str::FromStr,
+};
+
+use bitflags::bitflags;
+
+use crate::sys;
+
+/// An X11/xkbcommon keysym.
+///
+/// All the constants in the [`keys`](super::keys) module are keysyms, but any keysym
+/// can be looked up by its xkbcommon name with [`Keysym::from_name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Keysym(pub u32);
+
+impl Keysym {
+	/// Look up a keysym by its xkbcommon name (e.g. `"Return"`, `"F1"`, `"XF86Switch_VT_1"`).
+	///
+	/// An exact match is tried first, then a case-insensitive one.
+	///
+	/// # Returns
+	///
+	/// `None` if no keysym has this name
+	pub fn from_name(name: &str) -> Option<Self> {
+		let name = CString::new(name).ok()?;
+		let keysym = unsafe { sys::rmarKeysymFromName(name.as_ptr()) };
+		(keysym != 0).then_some(Self(keysym))
+	}
+
+	/// Get the xkbcommon name of this keysym.
+	///
+	/// # Returns
+	///
+	/// `None` if the keysym is invalid
+	pub fn name(&self) -> Option<String> {
+		let mut buffer = [0 as c_char; 64];
+		let len = unsafe { sys::rmarKeysymGetName(self.0, buffer.as_mut_ptr(), buffer.len()) };
+		if len == 0 {
+			return None;
+		}
+		let bytes = buffer[..len.min(buffer.len() - 1)]
+			.iter()
+			.map(|&c| c as u8)
+			.collect::<Vec<u8>>();
+		String::from_utf8(bytes).ok()
+	}
+
+	/// Get the raw keysym value, as used by xkbcommon.
+	pub fn raw(&self) -> u32 {
+		self.0
+	}
+}
+
+impl From<u32> for Keysym {
+	fn from(value: u32) -> Self {
+		Self(value)
+	}
+}
+
+impl From<Keysym> for u32 {
+	fn from(value: Keysym) -> Self {
+		value.0
+	}
+}
+
+impl FromStr for Keysym {
+	type Err = UnknownKeysymError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::from_name(s).ok_or_else(|| UnknownKeysymError(s.to_string()))
+	}
+}
+
+impl fmt::Display for Keysym {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.name() {
+			Some(name) => f.write_str(&name),
+			None => write!(f, "{:#x}", self.0),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKeysymError(pub String);
+
+impl fmt::Display for UnknownKeysymError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "unknown key or modifier: {:?}", self.0)
+	}
+}
+
+impl std::error::Error for UnknownKeysymError {}
+
+bitflags! {
+	/// Keyboard modifiers, as reported by the xkb state of the connected keyboards.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+	pub struct Modifiers: u32 {
+		const SHIFT = sys::RustamarineModifier_RMAR_MOD_SHIFT;
+		const CAPS_LOCK = sys::RustamarineModifier_RMAR_MOD_CAPS_LOCK;
+		const CTRL = sys::RustamarineModifier_RMAR_MOD_CTRL;
+		const ALT = sys::RustamarineModifier_RMAR_MOD_ALT;
+		const NUM_LOCK = sys::RustamarineModifier_RMAR_MOD_NUM_LOCK;
+		const SUPER = sys::RustamarineModifier_RMAR_MOD_SUPER;
+	}
+}
+
+impl Modifiers {
+	/// Modifiers that are toggled by a key press instead of being held down.
+	pub const LOCKS: Self = Self::CAPS_LOCK.union(Self::NUM_LOCK);
+
+	/// Look up a single modifier by name, as used in shortcut strings.
+	///
+	/// Accepts the common spellings (`"Ctrl"`, `"Control"`, `"Alt"`, `"Mod1"`, `"Super"`,
+	/// `"Logo"`, `"Mod4"`, `"Shift"`, `"CapsLock"`, `"NumLock"`), ignoring case.
+	pub fn from_modifier_name(name: &str) -> Option<Self> {
+		match name.to_ascii_lowercase().as_str() {
+			"shift" => Some(Self::SHIFT),
+			"ctrl" | "control" => Some(Self::CTRL),
+			"alt" | "mod1" => Some(Self::ALT),
+			"super" | "logo" | "win" | "mod4" => Some(Self::SUPER),
+			"capslock" | "caps_lock" | "lock" => Some(Self::CAPS_LOCK),
+			"numlock" | "num_lock" | "mod2" => Some(Self::NUM_LOCK),
+			_ => None,
+		}
+	}
+
+	/// Parse modifier names joined with `+`, like `"Ctrl+Alt"`.
+	///
+	/// Every name must be accepted by [`Modifiers::from_modifier_name`], an empty string
+	/// is no modifiers.
+	pub fn from_names(names: &str) -> Result<Self, UnknownKeysymError> {
+		if names.trim().is_empty() {
+			return Ok(Self::empty());
+		}
+		names
+			.split('+')
+			.map(str::trim)
+			.try_fold(Self::empty(), |acc, name| {
+				Self::from_modifier_name(name)
+					.map(|m| acc | m)
+					.ok_or_else(|| UnknownKeysymError(name.to_string()))
+			})
+	}
+
+	/// Get the name of a single modifier, in the spelling used for shortcut strings.
+	///
+	/// # Returns
+	///
+	/// `None` if `self` isn't exactly one modifier
+	pub fn modifier_name(&self) -> Option<&'static str> {
+		[
+			(Self::SHIFT, "Shift"),
+			(Self::CTRL, "Ctrl"),
+			(Self::ALT, "Alt"),
+			(Self::SUPER, "Super"),
+			(Self::CAPS_LOCK, "CapsLock"),
+			(Self::NUM_LOCK, "NumLock"),
+		]
+		.into_iter()
+		.find_map(|(modifier, name)| (modifier == *self).then_some(name))
+	}
+}
+
+/// A key combination such as `Ctrl+Alt+F1`, usually parsed from a config file.
+///
+/// The last component is the keysym name, everything before it must be a modifier
+/// accepted by [`Modifiers::from_modifier_name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+	pub modifiers: Modifiers,
+	pub keysym: Keysym,
+}
+
+impl Shortcut {
+	pub fn new(modifiers: Modifiers, keysym: Keysym) -> Self {
+		Self { modifiers, keysym }
+	}
+
+	/// Check whether `modifiers` satisfy this shortcut.
+	///
+	/// Lock modifiers are ignored, so Caps Lock or Num Lock being on doesn't break shortcuts.
+	pub fn matches_modifiers(&self, modifiers: Modifiers) -> bool {
+		modifiers.difference(Modifiers::LOCKS) == self.modifiers.difference(Modifiers::LOCKS)
+	}
+}
+
+impl FromStr for Shortcut {
+	type Err = UnknownKeysymError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (modifiers, key) = s.rsplit_once('+').unwrap_or(("", s));
+		let key = key.trim();
+		if key.is_empty() {
+			return Err(UnknownKeysymError(s.to_string()));
+		}
+		Ok(Self {
+			modifiers: Modifiers::from_names(modifiers)?,
+			keysym: key.parse()?,
+		})
+	}
+}
+
+impl fmt::Display for Shortcut {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for modifier in self.modifiers.iter() {
+			if let Some(name) = modifier.modifier_name() {
+				write!(f, "{name}+")?;
+			}
+		}
+		write!(f, "{}", self.keysym)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::input::keys::{KEY_Delete, KEY_F1};
+
+	#[test]
+	fn test_modifier_names() {
+		assert_eq!(
+			Modifiers::from_modifier_name("Control"),
+			Some(Modifiers::CTRL)
+		);
+		assert_eq!(
+			Modifiers::from_modifier_name("mod4"),
+			Some(Modifiers::SUPER)
+		);
+		assert_eq!(Modifiers::from_modifier_name("Hyper"), None);
+		for modifier in Modifiers::all().iter() {
+			let name = modifier.modifier_name().unwrap();
+			assert_eq!(Modifiers::from_modifier_name(name), Some(modifier));
+		}
+		assert_eq!((Modifiers::CTRL | Modifiers::ALT).modifier_name(), None);
+	}
+
+	#[test]
+	fn test_modifier_list() {
+		assert_eq!(
+			Modifiers::from_names("Super + alt"),
+			Ok(Modifiers::SUPER | Modifiers::ALT)
+		);
+		assert_eq!(Modifiers::from_names(""), Ok(Modifiers::empty()));
+		assert_eq!(
+			Modifiers::from_names("Ctrl+Hyper"),
+			Err(UnknownKeysymError("Hyper".to_string()))
+		);
+		assert!(Modifiers::from_names("Ctrl++Alt").is_err());
+	}
+
+	#[test]
+	fn test_shortcut_parsing() {
+		assert_eq!(
+			"Ctrl+Alt+F1".parse(),
+			Ok(Shortcut::new(Modifiers::CTRL | Modifiers::ALT, KEY_F1))
+		);
+		assert_eq!(
+			" super + Delete ".parse(),
+			Ok(Shortcut::new(Modifiers::SUPER, KEY_Delete))
+		);
+		assert_eq!("F1".parse(), Ok(Shortcut::new(Modifiers::empty(), KEY_F1)));
+		assert_eq!(
+			"Hyper+F1".parse::<Shortcut>(),
+			Err(UnknownKeysymError("Hyper".to_string()))
+		);
+		assert!("Ctrl+".parse::<Shortcut>().is_err());
+		assert!("Ctrl+NotAKey".parse::<Shortcut>().is_err());
+	}
+
+	#[test]
+	fn test_shortcut_round_trip() {
+		let shortcut = Shortcut::new(Modifiers::CTRL | Modifiers::ALT, KEY_F1);
+		assert_eq!(shortcut.to_string(), "Ctrl+Alt+F1");
+		assert_eq!(shortcut.to_string().parse(), Ok(shortcut));
+	}
+
+	#[test]
+	fn test_shortcut_ignores_locks() {
+		let shortcut = Shortcut::new(Modifiers::CTRL, KEY_F1);
+		assert!(shortcut.matches_modifiers(Modifiers::CTRL | Modifiers::NUM_LOCK));
+		assert!(!shortcut.matches_modifiers(Modifiers::CTRL | Modifiers::SHIFT));
+	}
+}
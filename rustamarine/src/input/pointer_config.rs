@@ -0,0 +1,78 @@
+use crate::{sys, Rustamarine};
+
+/// The kind of pointer device a [`PointerConfig`] applies to.
+///
+/// Devices that support tapping are considered touchpads, every other pointer is a mouse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeviceClass {
+	Mouse,
+	Touchpad,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccelProfile {
+	/// No acceleration, the pointer moves by a constant factor of the device movement
+	Flat,
+	/// The pointer moves faster the faster the device moves
+	Adaptive,
+}
+
+/// libinput settings for a class of pointer devices.
+///
+/// Every field that is `None` keeps the libinput default for that device.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PointerConfig {
+	pub tap_to_click: Option<bool>,
+	pub natural_scroll: Option<bool>,
+	pub left_handed: Option<bool>,
+	pub accel_profile: Option<AccelProfile>,
+	/// Pointer speed, from `-1.0` (slowest) to `1.0` (fastest)
+	pub accel_speed: Option<f64>,
+}
+
+impl PointerConfig {
+	/// Combine two configs, taking the settings of `other` where they are set.
+	pub fn merge(self, other: PointerConfig) -> PointerConfig {
+		PointerConfig {
+			tap_to_click: other.tap_to_click.or(self.tap_to_click),
+			natural_scroll: other.natural_scroll.or(self.natural_scroll),
+			left_handed: other.left_handed.or(self.left_handed),
+			accel_profile: other.accel_profile.or(self.accel_profile),
+			accel_speed: other.accel_speed.or(self.accel_speed),
+		}
+	}
+
+	fn to_sys(self) -> sys::RustamarinePointerConfig {
+		let tristate = |v: Option<bool>| v.map(|v| v as i8).unwrap_or(-1);
+		sys::RustamarinePointerConfig {
+			tapToClick: tristate(self.tap_to_click),
+			naturalScroll: tristate(self.natural_scroll),
+			leftHanded: tristate(self.left_handed),
+			accelProfile: match self.accel_profile {
+				None => -1,
+				Some(AccelProfile::Flat) => 0,
+				Some(AccelProfile::Adaptive) => 1,
+			},
+			setAccelSpeed: self.accel_speed.is_some(),
+			accelSpeed: self.accel_speed.unwrap_or_default(),
+		}
+	}
+}
+
+impl Rustamarine {
+	/// Configure every pointer device of a class, including the ones connected later.
+	///
+	/// Settings that the device doesn't support (e.g. tapping on a mouse) are ignored.
+	///
+	/// # Arguments
+	///
+	/// * `class` - Which devices the config applies to
+	/// * `config` - The libinput settings, replacing the ones previously set for this class
+	pub fn set_pointer_config(&self, class: DeviceClass, config: &PointerConfig) {
+		let class = match class {
+			DeviceClass::Mouse => sys::RustamarineDeviceClass_RMAR_DEVICE_MOUSE,
+			DeviceClass::Touchpad => sys::RustamarineDeviceClass_RMAR_DEVICE_TOUCHPAD,
+		};
+		unsafe { sys::rmarSetPointerConfig(self.inner, class, config.to_sys()) }
+	}
+}
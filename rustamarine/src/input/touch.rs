@@ -0,0 +1,49 @@
+use crate::{sys, Rustamarine};
+
+/// A finger on a touchscreen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchPoint {
+	/// Identifies the finger for as long as it touches the screen
+	pub id: i32,
+	/// X position in pixels of the screen the touchscreen covers
+	pub x: f32,
+	/// Y position in screen pixels
+	pub y: f32,
+	/// `true` for the frame where the finger touched the screen
+	pub pressed: bool,
+	/// `true` for the frame where the finger was lifted, this is the last frame the point is reported
+	pub released: bool,
+	/// Index of the screen the touchscreen covers, in the order of [`Rustamarine::screens`]
+	pub screen: usize,
+}
+
+impl Rustamarine {
+	/// Get every finger currently touching a touchscreen, ordered by id.
+	///
+	/// Each touchscreen is mapped to the screen udev assigned it to (`WL_OUTPUT`), or to the
+	/// first screen if it has none.
+	///
+	/// # Returns
+	///
+	/// The touch points, or an empty vector if nothing is touching the screen
+	pub fn get_touch_points(&self) -> Vec<TouchPoint> {
+		let count = unsafe { sys::rmarGetTouchPointCount(self.inner) };
+		(0..count)
+			.filter_map(|i| {
+				let mut point = std::mem::MaybeUninit::<sys::RustamarineTouchPoint>::uninit();
+				if !unsafe { sys::rmarGetTouchPoint(self.inner, i, point.as_mut_ptr()) } {
+					return None;
+				}
+				let point = unsafe { point.assume_init() };
+				Some(TouchPoint {
+					id: point.id,
+					x: point.x as f32,
+					y: point.y as f32,
+					pressed: point.justPressed,
+					released: point.justReleased,
+					screen: point.screen,
+				})
+			})
+			.collect()
+	}
+}
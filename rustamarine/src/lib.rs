@@ -9,8 +9,17 @@ mod sys;
 #[cfg(feature = "opengl_loader")]
 pub use sys::opengl;
 mod input;
-pub use input::keys;
+pub use input::{
+	keys, AccelProfile, DeviceClass, KeymapNames, Keysym, Modifiers, PointerConfig, Shortcut,
+	TouchPoint, UnknownKeysymError,
+};
 pub mod screen;
+mod seat;
+pub use seat::{SeatBackend, UnknownSeatBackendError};
+mod session;
+pub use session::SessionEvent;
+mod waker;
+pub use waker::Waker;
 pub struct Rustamarine {
 	inner: *mut sys::Rustamarine,
 }
@@ -34,7 +43,10 @@ impl Rustamarine {
 		unsafe { sys::rmarIsDRM(self.inner) }
 	}
 
-	pub fn go_to_tty(&mut self, tty: u16) {
+	/// Switch to another VT through the seat, releasing the display and input devices.
+	///
+	/// Only works when running on DRM, see [`Rustamarine::is_drm`].
+	pub fn go_to_tty(&self, tty: u16) {
 		unsafe { sys::rmarGoToTTY(self.inner, tty) }
 	}
 }
@@ -0,0 +1,86 @@
+use std::{ffi::c_char, fmt, str::FromStr};
+
+use crate::{sys, Rustamarine};
+
+/// How rustamarine gets access to the DRM and input devices.
+///
+/// Everything except [`SeatBackend::Direct`] goes through libseat, so rustamarine
+/// can run as an unprivileged user as long as it's allowed to use the seat.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SeatBackend {
+	/// Try seatd, then logind, then direct access
+	#[default]
+	Auto,
+	Seatd,
+	Logind,
+	/// Open the devices directly, requires root
+	Direct,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownSeatBackendError(pub String);
+
+impl fmt::Display for UnknownSeatBackendError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"Unknown seat backend '{}', expected auto, seatd, logind or direct",
+			self.0
+		)
+	}
+}
+
+impl std::error::Error for UnknownSeatBackendError {}
+
+impl SeatBackend {
+	/// Get the `LIBSEAT_BACKEND` value that selects this backend.
+	///
+	/// libseat only reads the backend to use from the environment, so the application
+	/// sets it before creating [`Rustamarine`].
+	///
+	/// # Returns
+	///
+	/// `None` for [`SeatBackend::Auto`], which leaves the choice to libseat
+	pub fn libseat_backend(&self) -> Option<&'static str> {
+		match self {
+			Self::Auto => None,
+			Self::Seatd => Some("seatd"),
+			Self::Logind => Some("logind"),
+			Self::Direct => Some("noop"),
+		}
+	}
+}
+
+impl FromStr for SeatBackend {
+	type Err = UnknownSeatBackendError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_ascii_lowercase().as_str() {
+			"auto" => Ok(Self::Auto),
+			"seatd" => Ok(Self::Seatd),
+			"logind" => Ok(Self::Logind),
+			"direct" | "builtin" | "noop" => Ok(Self::Direct),
+			_ => Err(UnknownSeatBackendError(s.to_string())),
+		}
+	}
+}
+
+impl Rustamarine {
+	/// Get the name of the seat the devices were acquired from (usually `seat0`).
+	///
+	/// # Returns
+	///
+	/// `None` when not running on DRM
+	pub fn seat_name(&self) -> Option<String> {
+		let mut buffer = [0 as c_char; 64];
+		let len = unsafe { sys::rmarGetSeatName(self.inner, buffer.as_mut_ptr(), buffer.len()) };
+		if len == 0 {
+			return None;
+		}
+		let bytes = buffer[..len.min(buffer.len() - 1)]
+			.iter()
+			.map(|&c| c as u8)
+			.collect::<Vec<u8>>();
+		String::from_utf8(bytes).ok()
+	}
+}
@@ -0,0 +1,35 @@
+use crate::{sys, Rustamarine};
+
+/// A change of the session state, caused by switching VTs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEvent {
+	/// Switched back to our VT, the display and input devices can be used again
+	Activated,
+	/// Switched to another VT, DRM master and the input devices were released
+	Deactivated,
+}
+
+impl Rustamarine {
+	/// Check if we own the display and input devices.
+	///
+	/// This is `false` while another VT is active. Backends without a session
+	/// (e.g. nested in a Wayland compositor) are always active.
+	pub fn is_session_active(&self) -> bool {
+		unsafe { sys::rmarIsOnOriginalTTY(self.inner) }
+	}
+
+	/// Get the next session event that happened since the last call.
+	///
+	/// # Returns
+	///
+	/// `None` if there are no more pending events
+	pub fn poll_session_event(&self) -> Option<SessionEvent> {
+		match unsafe { sys::rmarPollSessionEvent(self.inner) } {
+			sys::RustamarineSessionEvent_RMAR_SESSION_EVENT_ACTIVATED => Some(SessionEvent::Activated),
+			sys::RustamarineSessionEvent_RMAR_SESSION_EVENT_DEACTIVATED => {
+				Some(SessionEvent::Deactivated)
+			}
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,35 @@
+use std::{
+	fs::File,
+	io::{self, Write},
+	os::fd::BorrowedFd,
+	sync::Arc,
+};
+
+use crate::{sys, Rustamarine};
+
+/// Makes [`Rustamarine::poll_events`] return early, from any thread.
+///
+/// Useful for waking up the main loop when something other than input or a frame
+/// needs handling, like a background thread finishing its work.
+#[derive(Debug, Clone)]
+pub struct Waker {
+	eventfd: Arc<File>,
+}
+
+impl Waker {
+	/// Wake up the current or next [`Rustamarine::poll_events`] call.
+	pub fn wake(&self) {
+		// Only fails if the counter would overflow, and then a wakeup is pending anyway
+		let _ = (&*self.eventfd).write_all(&1u64.to_ne_bytes());
+	}
+}
+
+impl Rustamarine {
+	/// Get a [`Waker`] for this instance's event loop. It stays safe to use after rustamarine is dropped.
+	pub fn waker(&self) -> io::Result<Waker> {
+		let fd = unsafe { BorrowedFd::borrow_raw(sys::rmarGetWakeupFD(self.inner)) };
+		Ok(Waker {
+			eventfd: Arc::new(File::from(fd.try_clone_to_owned()?)),
+		})
+	}
+}
@@ -0,0 +1,68 @@
+use pam::Client;
+use std::fmt;
+
+const DEFAULT_ADMIN_GROUP: &str = "wheel";
+
+/// Why someone couldn't prove they're an administrator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdminAuthError {
+	/// PAM doesn't tell which one was wrong
+	WrongCredentials,
+	NotAdmin {
+		name: String,
+		group: String,
+	},
+	Pam(String),
+}
+
+impl fmt::Display for AdminAuthError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			AdminAuthError::WrongCredentials => write!(f, "Wrong user name or password"),
+			AdminAuthError::NotAdmin { name, group } => {
+				write!(f, "{name} isn't in the {group} group")
+			}
+			AdminAuthError::Pam(e) => write!(f, "Failed to start PAM: {e}"),
+		}
+	}
+}
+
+/// The group whose members count as administrators, from `TIBS_ADMIN_GROUP`.
+fn admin_group() -> String {
+	std::env::var("TIBS_ADMIN_GROUP")
+		.ok()
+		.map(|s| s.trim().to_string())
+		.filter(|s| !s.is_empty())
+		.unwrap_or_else(|| DEFAULT_ADMIN_GROUP.to_string())
+}
+
+/// Check the password of a user with PAM, and that they're in the admin group.
+///
+/// Blocks for as long as PAM takes, which is a few seconds after a wrong password.
+pub fn authenticate(name: &str, password: &str) -> Result<(), AdminAuthError> {
+	let mut client =
+		Client::with_password("login").map_err(|e| AdminAuthError::Pam(format!("{e:?}")))?;
+	client.conversation_mut().set_credentials(name, password);
+	client
+		.authenticate()
+		.map_err(|_| AdminAuthError::WrongCredentials)?;
+	let group = admin_group();
+	if !is_member(name, &group) {
+		return Err(AdminAuthError::NotAdmin {
+			name: name.to_string(),
+			group,
+		});
+	}
+	Ok(())
+}
+
+fn is_member(name: &str, group: &str) -> bool {
+	let Some(user) = uzers::get_user_by_name(name) else {
+		return false;
+	};
+	// Includes the primary group, which /etc/group doesn't list members for
+	uzers::get_user_groups(user.name(), user.primary_group_id())
+		.unwrap_or_default()
+		.iter()
+		.any(|g| g.name() == group)
+}
@@ -5,7 +5,7 @@ use rand::Rng;
 use skia_safe::{Canvas, Paint, Rect, RuntimeEffect};
 
 use crate::{
-	all, animation::{self, colors::{self, interpolate_color_normalized}, easing, Animation, BasicAnimation}, login::{LoginManager, LoginState}, login_screen, skia_shader_asset::SkiaShaderAsset
+	all, animation::{self, colors::{self, interpolate_color_normalized}, easing, Animation, BasicAnimation}, skia_shader_asset::SkiaShaderAsset
 };
 
 pub struct Background {
@@ -40,7 +40,8 @@ impl Background {
 			success_animation_target
 		}
 	}
-	pub fn update(&mut self, delta: f32, login_manager: &LoginManager, login_screen: &login_screen::LoginScreen) {
+	/// `authenticated` plays the success animation, while the selected user is logging in
+	pub fn update(&mut self, delta: f32, authenticated: bool) {
 		// Incrementa o tempo com base na velocidade
 		self.elapsed_time += delta;
 
@@ -51,14 +52,10 @@ impl Background {
 		self
 			.animations_state
 			.extend(self.success_animation.update(delta));
-		let login_state = login_manager.get_current_login_state(login_screen.username());
-		match login_state {
-			Some(LoginState::Authenticated(_)) => {
-				self.success_animation_target.send(1.0);
-			},
-			_ => {
-				self.success_animation_target.send(0.0);
-			}
+		if authenticated {
+			self.success_animation_target.send(1.0);
+		} else {
+			self.success_animation_target.send(0.0);
 		}
 	}
 	pub fn render(&self, canvas: &Canvas) {
@@ -0,0 +1,128 @@
+use rustamarine::Waker;
+use smol::channel;
+use std::thread;
+use zbus::{connection, fdo, interface, message::Header, Connection};
+
+const BUS_NAME: &str = "io.github.coffeeispower.Tibs";
+const OBJECT_PATH: &str = "/io/github/coffeeispower/Tibs";
+
+/// Something a running session asked tibs to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GreeterRequest {
+	/// Show the greeter so another user can log in, leaving the current session running
+	SwitchUser {
+		/// Who asked, from the bus daemon
+		caller_uid: u32,
+	},
+}
+
+/// A request the main loop has to accept or deny, the caller waits for the answer.
+pub struct PendingRequest {
+	pub request: GreeterRequest,
+	reply: channel::Sender<bool>,
+}
+
+impl PendingRequest {
+	pub fn accept(self) {
+		let _ = self.reply.try_send(true);
+	}
+
+	pub fn deny(self) {
+		let _ = self.reply.try_send(false);
+	}
+}
+
+struct Greeter {
+	requests: channel::Sender<PendingRequest>,
+	/// The main loop only checks for requests when it wakes up
+	waker: Waker,
+}
+
+#[interface(name = "io.github.coffeeispower.Tibs.Greeter")]
+impl Greeter {
+	async fn switch_user(
+		&self,
+		#[zbus(header)] header: Header<'_>,
+		#[zbus(connection)] connection: &Connection,
+	) -> fdo::Result<()> {
+		let sender = header
+			.sender()
+			.ok_or_else(|| fdo::Error::Failed("The call has no sender".into()))?;
+		let caller_uid = fdo::DBusProxy::new(connection)
+			.await?
+			.get_connection_unix_user(sender.clone().into())
+			.await?;
+		let shutting_down = || fdo::Error::Failed("The greeter is shutting down".into());
+		let (reply_tx, reply_rx) = channel::bounded(1);
+		self
+			.requests
+			.send(PendingRequest {
+				request: GreeterRequest::SwitchUser { caller_uid },
+				reply: reply_tx,
+			})
+			.await
+			.map_err(|_| shutting_down())?;
+		self.waker.wake();
+		match reply_rx.recv().await {
+			Ok(true) => Ok(()),
+			Ok(false) => Err(fdo::Error::AccessDenied(
+				"Only the user of the session on screen can switch users".into(),
+			)),
+			Err(_) => Err(shutting_down()),
+		}
+	}
+}
+
+/// Serves the tibs D-Bus interface on the system bus, so sessions can get back to the greeter.
+pub struct GreeterService {
+	request_rx: channel::Receiver<PendingRequest>,
+}
+
+impl GreeterService {
+	pub fn new(waker: Waker) -> Self {
+		let (request_tx, request_rx) = channel::unbounded::<PendingRequest>();
+		thread::spawn(move || {
+			let result: zbus::Result<()> = smol::block_on(async {
+				let _connection = connection::Builder::system()?
+					.name(BUS_NAME)?
+					.serve_at(
+						OBJECT_PATH,
+						Greeter {
+							requests: request_tx,
+							waker,
+						},
+					)?
+					.build()
+					.await?;
+				// The connection answers calls in the background for as long as it lives
+				std::future::pending::<()>().await;
+				Ok(())
+			});
+			if let Err(e) = result {
+				log::warn!("Failed to serve {BUS_NAME} on the system bus: {e}");
+			}
+		});
+		Self { request_rx }
+	}
+
+	pub fn poll_request(&self) -> Option<PendingRequest> {
+		self.request_rx.try_recv().ok()
+	}
+}
+
+/// Ask the running tibs instance to show the greeter, used by `tibs switch-user`.
+pub fn request_switch_user() -> zbus::Result<()> {
+	smol::block_on(async {
+		let connection = Connection::system().await?;
+		connection
+			.call_method(
+				Some(BUS_NAME),
+				OBJECT_PATH,
+				Some("io.github.coffeeispower.Tibs.Greeter"),
+				"SwitchUser",
+				&(),
+			)
+			.await?;
+		Ok(())
+	})
+}
@@ -0,0 +1,188 @@
+use rustamarine::{AccelProfile, DeviceClass, PointerConfig, Rustamarine};
+use std::{fs, path::Path};
+
+const XORG_CONF_DIR: &str = "/etc/X11/xorg.conf.d";
+
+/// Configure mice and touchpads.
+///
+/// The libinput options from the X11 config are used as a base, and can be overridden
+/// with `TIBS_TOUCHPAD_*` and `TIBS_MOUSE_*` environment variables
+/// (`TAP_TO_CLICK`, `NATURAL_SCROLL`, `LEFT_HANDED`, `ACCEL_PROFILE`, `ACCEL_SPEED`).
+pub fn apply_pointer_config(rmar: &Rustamarine) {
+	let (xorg_touchpad, xorg_mouse) = xorg_pointer_configs(Path::new(XORG_CONF_DIR));
+	let touchpad = xorg_touchpad.merge(pointer_config_from_env("TIBS_TOUCHPAD"));
+	let mouse = xorg_mouse.merge(pointer_config_from_env("TIBS_MOUSE"));
+	if touchpad != PointerConfig::default() {
+		rmar.set_pointer_config(DeviceClass::Touchpad, &touchpad);
+	}
+	if mouse != PointerConfig::default() {
+		rmar.set_pointer_config(DeviceClass::Mouse, &mouse);
+	}
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+	match value.trim().to_ascii_lowercase().as_str() {
+		"1" | "on" | "true" | "yes" => Some(true),
+		"0" | "off" | "false" | "no" => Some(false),
+		_ => None,
+	}
+}
+
+fn parse_accel_profile(value: &str) -> Option<AccelProfile> {
+	match value.trim().to_ascii_lowercase().as_str() {
+		"flat" => Some(AccelProfile::Flat),
+		"adaptive" => Some(AccelProfile::Adaptive),
+		_ => None,
+	}
+}
+
+fn pointer_config_from_env(prefix: &str) -> PointerConfig {
+	let var = |name: &str| std::env::var(format!("{prefix}_{name}")).ok();
+	PointerConfig {
+		tap_to_click: var("TAP_TO_CLICK").as_deref().and_then(parse_bool),
+		natural_scroll: var("NATURAL_SCROLL").as_deref().and_then(parse_bool),
+		left_handed: var("LEFT_HANDED").as_deref().and_then(parse_bool),
+		accel_profile: var("ACCEL_PROFILE").as_deref().and_then(parse_accel_profile),
+		accel_speed: var("ACCEL_SPEED").and_then(|s| s.trim().parse().ok()),
+	}
+}
+
+/// Read the libinput options of every `InputClass` section in the X11 config directory.
+///
+/// Returns the (touchpad, mouse) configs. Files are read in lexical order, so later
+/// files override earlier ones like Xorg does.
+fn xorg_pointer_configs(dir: &Path) -> (PointerConfig, PointerConfig) {
+	let mut files = fs::read_dir(dir)
+		.map(|entries| {
+			entries
+				.filter_map(Result::ok)
+				.map(|e| e.path())
+				.filter(|p| p.extension().is_some_and(|e| e == "conf"))
+				.collect::<Vec<_>>()
+		})
+		.unwrap_or_default();
+	files.sort();
+	files
+		.iter()
+		.filter_map(|path| fs::read_to_string(path).ok())
+		.fold(Default::default(), |(touchpad, mouse), contents| {
+			let (t, m) = parse_xorg_pointer_config(&contents);
+			(touchpad.merge(t), mouse.merge(m))
+		})
+}
+
+fn parse_xorg_pointer_config(contents: &str) -> (PointerConfig, PointerConfig) {
+	let mut touchpad = PointerConfig::default();
+	let mut mouse = PointerConfig::default();
+	let mut section: Option<(PointerConfig, bool, bool)> = None;
+	for line in contents.lines().map(str::trim) {
+		let mut words = line.split('"').map(str::trim).filter(|p| !p.is_empty());
+		let Some(keyword) = words.next() else {
+			continue;
+		};
+		match keyword.to_ascii_lowercase().as_str() {
+			"section" if words.next().is_some_and(|s| s.eq_ignore_ascii_case("InputClass")) => {
+				section = Some((PointerConfig::default(), false, false));
+			}
+			"endsection" => {
+				if let Some((config, is_touchpad, is_pointer)) = section.take() {
+					if is_touchpad || is_pointer {
+						touchpad = touchpad.merge(config);
+					}
+					if is_pointer {
+						mouse = mouse.merge(config);
+					}
+				}
+			}
+			"matchistouchpad" => {
+				if let Some((_, is_touchpad, _)) = &mut section {
+					*is_touchpad = words.next().and_then(parse_bool).unwrap_or(false);
+				}
+			}
+			"matchispointer" => {
+				if let Some((_, _, is_pointer)) = &mut section {
+					*is_pointer = words.next().and_then(parse_bool).unwrap_or(false);
+				}
+			}
+			"option" => {
+				let (Some((config, _, _)), Some(name), Some(value)) =
+					(&mut section, words.next(), words.next())
+				else {
+					continue;
+				};
+				// Values that don't parse are skipped, keeping any earlier setting
+				match name.to_ascii_lowercase().as_str() {
+					"tapping" => config.tap_to_click = parse_bool(value).or(config.tap_to_click),
+					"naturalscrolling" => config.natural_scroll = parse_bool(value).or(config.natural_scroll),
+					"lefthanded" => config.left_handed = parse_bool(value).or(config.left_handed),
+					"accelprofile" => {
+						config.accel_profile = parse_accel_profile(value).or(config.accel_profile)
+					}
+					"accelspeed" => config.accel_speed = value.parse().ok().or(config.accel_speed),
+					_ => {}
+				}
+			}
+			_ => {}
+		}
+	}
+	(touchpad, mouse)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_xorg_pointer_config() {
+		let (touchpad, mouse) = parse_xorg_pointer_config(
+			r#"
+Section "InputClass"
+        Identifier "touchpad"
+        MatchIsTouchpad "on"
+        Driver "libinput"
+        Option "Tapping" "on"
+        Option "NaturalScrolling" "true"
+EndSection
+
+Section "InputClass"
+        Identifier "pointers"
+        MatchIsPointer "yes"
+        Option "AccelProfile" "flat"
+        Option "AccelSpeed" "-0.5"
+EndSection
+
+Section "InputClass"
+        Identifier "keyboard"
+        MatchIsKeyboard "on"
+        Option "LeftHanded" "on"
+EndSection
+"#,
+		);
+		assert_eq!(touchpad.tap_to_click, Some(true));
+		assert_eq!(touchpad.natural_scroll, Some(true));
+		assert_eq!(touchpad.accel_profile, Some(AccelProfile::Flat));
+		assert_eq!(touchpad.left_handed, None);
+		assert_eq!(mouse.tap_to_click, None);
+		assert_eq!(mouse.accel_speed, Some(-0.5));
+		assert_eq!(mouse.left_handed, None);
+	}
+
+	#[test]
+	fn test_xorg_pointer_config_invalid_value() {
+		let (touchpad, mouse) = parse_xorg_pointer_config(
+			r#"
+Section "InputClass"
+        Identifier "pointers"
+        MatchIsPointer "yes"
+        Option "AccelSpeed" "-0.5"
+        Option "Tapping" "on"
+        Option "AccelSpeed" "fast"
+        Option "Tapping" "sometimes"
+EndSection
+"#,
+		);
+		assert_eq!(mouse.accel_speed, Some(-0.5));
+		assert_eq!(mouse.tap_to_click, Some(true));
+		assert_eq!(touchpad.accel_speed, Some(-0.5));
+	}
+}
@@ -0,0 +1,122 @@
+use nix::libc;
+use std::{
+	collections::VecDeque,
+	fs::{File, OpenOptions},
+	io::{self, Read},
+	os::unix::fs::OpenOptionsExt,
+	time::Duration,
+};
+
+const KMSG_PATH: &str = "/dev/kmsg";
+/// Older messages are dropped, the kernel's own buffer has them anyway
+const MAX_MESSAGES: usize = 500;
+/// Messages at this level or more important are highlighted, 4 is `KERN_WARNING`
+pub const WARNING_LEVEL: u8 = 4;
+
+/// One record of the kernel log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelMessage {
+	/// Syslog level, 0 is an emergency and 7 is debug
+	pub level: u8,
+	/// Formatted like dmesg, `[    1.234567] message`
+	pub text: String,
+}
+
+impl KernelMessage {
+	/// Parse a `/dev/kmsg` record, like `6,339,5140900,-;NET: Registered PF_INET protocol family`.
+	fn parse(record: &str) -> Option<Self> {
+		let (prefix, message) = record.split_once(';')?;
+		let mut fields = prefix.split(',');
+		let priority = fields.next()?.parse::<u32>().ok()?;
+		let _sequence = fields.next()?;
+		let time = Duration::from_micros(fields.next()?.parse().ok()?);
+		// Continuation lines hold key=value pairs for the device, not part of the message
+		let message = message.lines().next().unwrap_or_default();
+		Some(Self {
+			level: (priority & 7) as u8,
+			text: format!(
+				"[{:5}.{:06}] {message}",
+				time.as_secs(),
+				time.subsec_micros()
+			),
+		})
+	}
+}
+
+/// Follows `/dev/kmsg` without blocking, starting from the oldest message the kernel still has.
+pub struct KernelLog {
+	file: Option<File>,
+	messages: VecDeque<KernelMessage>,
+}
+
+impl KernelLog {
+	pub fn open() -> Self {
+		let file = OpenOptions::new()
+			.read(true)
+			.custom_flags(libc::O_NONBLOCK)
+			.open(KMSG_PATH)
+			.inspect_err(|e| log::warn!("Failed to open {KMSG_PATH}: {e}"))
+			.ok();
+		Self {
+			file,
+			messages: VecDeque::new(),
+		}
+	}
+
+	/// Read the messages that arrived since the last poll.
+	pub fn poll(&mut self) {
+		let Some(file) = &mut self.file else {
+			return;
+		};
+		// Every read returns exactly one record
+		let mut buffer = [0u8; 8192];
+		loop {
+			match file.read(&mut buffer) {
+				Ok(0) => break,
+				Ok(n) => {
+					let record = String::from_utf8_lossy(&buffer[..n]);
+					if let Some(message) = KernelMessage::parse(&record) {
+						if self.messages.len() == MAX_MESSAGES {
+							self.messages.pop_front();
+						}
+						self.messages.push_back(message);
+					}
+				}
+				Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+				// Some messages were overwritten before we got to them, the next read continues after them
+				Err(e) if e.raw_os_error() == Some(libc::EPIPE) => continue,
+				Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+				Err(e) => {
+					log::warn!("Failed to read {KMSG_PATH}, not following it anymore: {e}");
+					self.file = None;
+					break;
+				}
+			}
+		}
+	}
+
+	pub fn messages(&self) -> impl Iterator<Item = &KernelMessage> {
+		self.messages.iter()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_kmsg_record() {
+		let message = KernelMessage::parse(
+			"3,1024,5140900,-;usb 1-1: device descriptor read/64, error -71\n SUBSYSTEM=usb\n",
+		)
+		.unwrap();
+		assert_eq!(message.level, 3);
+		assert_eq!(
+			message.text,
+			"[    5.140900] usb 1-1: device descriptor read/64, error -71"
+		);
+		// Facility bits are above the level
+		assert_eq!(KernelMessage::parse("30,1,0,-;systemd").unwrap().level, 6);
+		assert_eq!(KernelMessage::parse("no separator"), None);
+	}
+}
@@ -0,0 +1,137 @@
+use rustamarine::KeymapNames;
+use std::fs;
+
+const VCONSOLE_CONF: &str = "/etc/vconsole.conf";
+const XORG_KEYBOARD_CONF: &str = "/etc/X11/xorg.conf.d/00-keyboard.conf";
+
+/// Read the system keyboard layout, as configured with `localectl set-x11-keymap`.
+///
+/// The xkb settings in `/etc/vconsole.conf` are tried first, then the X11 keyboard config.
+/// If neither has a layout, it's guessed from the console keymap.
+pub fn system_keymap() -> Option<KeymapNames> {
+	let vconsole = fs::read_to_string(VCONSOLE_CONF).ok();
+	vconsole
+		.as_deref()
+		.and_then(parse_vconsole_conf)
+		.or_else(|| {
+			fs::read_to_string(XORG_KEYBOARD_CONF)
+				.ok()
+				.and_then(|s| parse_xorg_keyboard_conf(&s))
+		})
+		.or_else(|| vconsole.as_deref().and_then(guess_from_console_keymap))
+}
+
+fn unquote(value: &str) -> &str {
+	let value = value.trim();
+	value
+		.strip_prefix('"')
+		.and_then(|v| v.strip_suffix('"'))
+		.or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+		.unwrap_or(value)
+}
+
+fn vconsole_entries(contents: &str) -> impl Iterator<Item = (&str, &str)> {
+	contents
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.starts_with('#'))
+		.filter_map(|line| line.split_once('='))
+		.map(|(key, value)| (key.trim(), unquote(value)))
+}
+
+fn parse_vconsole_conf(contents: &str) -> Option<KeymapNames> {
+	let mut names = KeymapNames::default();
+	for (key, value) in vconsole_entries(contents) {
+		let value = value.to_string();
+		match key {
+			"XKBLAYOUT" => names.layout = value,
+			"XKBMODEL" => names.model = value,
+			"XKBVARIANT" => names.variant = value,
+			"XKBOPTIONS" => names.options = value,
+			_ => {}
+		}
+	}
+	(!names.layout.is_empty()).then_some(names)
+}
+
+/// Console keymaps are named like "pt-latin1" or "de-nodeadkeys", the part before
+/// the dash is usually the xkb layout.
+fn guess_from_console_keymap(contents: &str) -> Option<KeymapNames> {
+	let (_, keymap) = vconsole_entries(contents)
+		.filter(|(key, _)| *key == "KEYMAP")
+		.last()?;
+	let layout = keymap.split('-').next().filter(|l| !l.is_empty())?;
+	Some(KeymapNames {
+		layout: layout.to_string(),
+		..Default::default()
+	})
+}
+
+fn parse_xorg_keyboard_conf(contents: &str) -> Option<KeymapNames> {
+	let mut names = KeymapNames::default();
+	for line in contents.lines().map(str::trim) {
+		let Some(option) = line.strip_prefix("Option") else {
+			continue;
+		};
+		let mut parts = option.split('"').map(str::trim).filter(|p| !p.is_empty());
+		let (Some(key), Some(value)) = (parts.next(), parts.next()) else {
+			continue;
+		};
+		let value = value.to_string();
+		match key.to_ascii_lowercase().as_str() {
+			"xkbrules" => names.rules = value,
+			"xkblayout" => names.layout = value,
+			"xkbmodel" => names.model = value,
+			"xkbvariant" => names.variant = value,
+			"xkboptions" => names.options = value,
+			_ => {}
+		}
+	}
+	(!names.layout.is_empty()).then_some(names)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_vconsole_conf() {
+		let names = parse_vconsole_conf(
+			"KEYMAP=pt-latin1\nXKBLAYOUT=\"us,pt\"\nXKBVARIANT=,nodeadkeys\nXKBOPTIONS=grp:alt_shift_toggle\n",
+		)
+		.unwrap();
+		assert_eq!(names.layout, "us,pt");
+		assert_eq!(names.variant, ",nodeadkeys");
+		assert_eq!(names.options, "grp:alt_shift_toggle");
+		assert_eq!(names.layouts(), vec!["us", "pt"]);
+	}
+
+	#[test]
+	fn test_vconsole_conf_console_keymap_only() {
+		let contents = "# comment\nKEYMAP=de-nodeadkeys\nFONT=eurlatgr\n";
+		assert!(parse_vconsole_conf(contents).is_none());
+		assert_eq!(guess_from_console_keymap(contents).unwrap().layout, "de");
+		assert!(guess_from_console_keymap("FONT=eurlatgr\n").is_none());
+	}
+
+	#[test]
+	fn test_xorg_keyboard_conf() {
+		let names = parse_xorg_keyboard_conf(
+			r#"
+# Written by systemd-localed(8)
+Section "InputClass"
+        Identifier "system-keyboard"
+        MatchIsKeyboard "on"
+        Option "XkbLayout" "pt,us"
+        Option "XkbModel" "pc105"
+        Option "XkbOptions" "terminate:ctrl_alt_bksp"
+EndSection
+"#,
+		)
+		.unwrap();
+		assert_eq!(names.layout, "pt,us");
+		assert_eq!(names.model, "pc105");
+		assert_eq!(names.options, "terminate:ctrl_alt_bksp");
+		assert!(names.rules.is_empty());
+	}
+}
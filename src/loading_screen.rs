@@ -1,4 +1,11 @@
-use std::{collections::HashMap, sync::mpsc::Sender};
+mod failed_units;
+use failed_units::FailedUnitInspector;
+
+use std::{
+	collections::HashMap,
+	sync::mpsc::Sender,
+	time::{Duration, Instant},
+};
 
 use assets_manager::AssetCache;
 use clay_layout::{
@@ -10,6 +17,7 @@ use clay_layout::{
 	text::TextConfig,
 	Declaration,
 };
+use rustamarine::Rustamarine;
 
 use crate::{
 	all,
@@ -19,11 +27,25 @@ use crate::{
 		easing::{ease_out_elastic, ease_out_quad, ease_out_quint},
 		Animation, BasicAnimation, ParallelAnimation, ProgressBarAnimation,
 	},
-	progress_watcher::ProgressData,
+	kernel_log::{self, KernelLog},
+	pointer::Pointer,
+	progress_watcher::{ProgressData, ServiceState, Transition},
 	seq,
 	skia_image_asset::SkiaImageAsset,
 	TibsClayScope,
 };
+
+/// Start jobs running for longer than this get systemd's "A start job is running" line
+const SLOW_JOB_THRESHOLD: Duration = Duration::from_secs(5);
+const MAX_JOB_LINES: usize = 3;
+const DETAILS_WIDTH: f32 = 560.0;
+
+/// A running job shown under the progress bar.
+struct JobLine {
+	text: String,
+	slow: bool,
+}
+
 pub struct LoadingScreen {
 	loading_animation: ParallelAnimation,
 	end_progress: BasicAnimation,
@@ -32,6 +54,18 @@ pub struct LoadingScreen {
 	logo: skia_safe::Image,
 	alert_icon: skia_safe::Image,
 	success_icon: skia_safe::Image,
+	/// Shown under the progress bar, like "Shutting down"
+	caption: Option<&'static str>,
+	transition: Transition,
+	/// The jobs running the longest, refreshed every update
+	job_lines: Vec<JobLine>,
+	/// Why the progress may be wrong, like losing the connection to systemd
+	error: Option<String>,
+	details_open: bool,
+	details_animation: BasicAnimation,
+	/// Opened the first time the details are shown
+	kernel_log: Option<KernelLog>,
+	failed_units: FailedUnitInspector,
 }
 
 impl LoadingScreen {
@@ -57,19 +91,77 @@ impl LoadingScreen {
 			success_icon,
 			alert_icon,
 			logo,
+			caption: None,
+			transition: Transition::Boot,
+			job_lines: Vec::new(),
+			error: None,
+			details_open: false,
+			details_animation: BasicAnimation::new("details", 0.3, ease_out_quint),
+			kernel_log: None,
+			failed_units: FailedUnitInspector::new(),
 		}
 	}
-	pub fn update(&mut self, progress: &ProgressData, delta_time: f32) {
+	pub fn set_caption(&mut self, caption: &'static str) {
+		self.caption = Some(caption);
+	}
+	pub fn set_transition(&mut self, transition: Transition) {
+		self.transition = transition;
+	}
+	/// Show or hide the list of units and kernel messages, like pressing Esc in Plymouth.
+	pub fn toggle_details(&mut self) {
+		self.details_open = !self.details_open;
+		if self.details_open && self.kernel_log.is_none() {
+			self.kernel_log = Some(KernelLog::open());
+		}
+	}
+	pub fn details_open(&self) -> bool {
+		self.details_open
+	}
+	/// Whether the failed units are shown, which takes the keyboard for the admin login.
+	pub fn failed_units_open(&self) -> bool {
+		self.failed_units.is_open()
+	}
+	pub fn update(
+		&mut self,
+		c: &mut clay_layout::Clay,
+		rmar: &Rustamarine,
+		pointer: &Pointer,
+		progress: &ProgressData,
+		delta_time: f32,
+	) {
 		self
 			.progress_bar_sender
 			.send(progress.get_percentage())
 			.unwrap();
+		self.job_lines = if progress.finished {
+			Vec::new()
+		} else {
+			self.running_jobs(progress)
+		};
+		self.error = progress.error.as_ref().map(ToString::to_string);
 		self
 			.animations_state
 			.extend(self.loading_animation.update(delta_time));
 		self
 			.animations_state
 			.extend(self.end_progress.update(delta_time));
+		self
+			.animations_state
+			.extend(self.details_animation.update(if self.details_open {
+				delta_time
+			} else {
+				-delta_time
+			}));
+		if self.details_open {
+			if let Some(kernel_log) = &mut self.kernel_log {
+				kernel_log.poll();
+			}
+		}
+		if pointer.pressed && c.pointer_over(c.id("check_logs_button_id")) {
+			self.failed_units.open(progress);
+		} else {
+			self.failed_units.update(c, rmar, pointer, progress);
+		}
 	}
 
 	pub fn render<'clay, 'render>(
@@ -101,6 +193,41 @@ impl LoadingScreen {
 					progress.finished.then_some(&self.success_icon),
 					c,
 				);
+				if let Some(caption) = self.caption {
+					c.with(
+						Declaration::new().layout().height(fixed!(22.0)).end(),
+						|_| {},
+					);
+					c.text(
+						caption,
+						TextConfig::new()
+							.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+							.font_size(14)
+							.alignment(clay_layout::text::TextAlignment::Center)
+							.end(),
+					);
+				}
+				if let Some(error) = &self.error {
+					c.with(
+						Declaration::new().layout().height(fixed!(22.0)).end(),
+						|_| {},
+					);
+					c.text(
+						error,
+						TextConfig::new()
+							.color((0xFF, 0xCC, 0x00).into())
+							.font_size(14)
+							.alignment(clay_layout::text::TextAlignment::Center)
+							.end(),
+					);
+				}
+				if !self.job_lines.is_empty() {
+					c.with(
+						Declaration::new().layout().height(fixed!(22.0)).end(),
+						|_| {},
+					);
+					self.job_lines(c);
+				}
 				if progress.has_failed_services() && progress.finished {
 					c.with(
 						Declaration::new().layout().height(fixed!(22.0)).end(),
@@ -108,6 +235,251 @@ impl LoadingScreen {
 					);
 					self.warning(c, end_progress_animation);
 				}
+				let details_animation = self.get_animation_progress("details");
+				if details_animation > 0.0 {
+					self.details(progress, details_animation, c);
+				}
+				self.failed_units.render(c);
+			},
+		);
+	}
+
+	fn running_jobs(&self, progress: &ProgressData) -> Vec<JobLine> {
+		let now = Instant::now();
+		let mut running = progress
+			.services
+			.iter()
+			.filter(|(_, &state)| state == ServiceState::Loading)
+			.map(|(unit, _)| {
+				let running_for = progress
+					.timings
+					.get(unit)
+					.map(|t| now.saturating_duration_since(t.started))
+					.unwrap_or_default();
+				(unit, running_for)
+			})
+			.collect::<Vec<_>>();
+		running.sort_by(|(a, a_running_for), (b, b_running_for)| {
+			b_running_for.cmp(a_running_for).then(a.cmp(b))
+		});
+		let verb = match self.transition {
+			Transition::Boot => "start",
+			Transition::Shutdown => "stop",
+		};
+		running
+			.into_iter()
+			.take(MAX_JOB_LINES)
+			.map(|(unit, running_for)| {
+				let info = progress.unit_info.get(unit);
+				let description = info
+					.map(|info| info.description.as_str())
+					.filter(|description| !description.is_empty())
+					.unwrap_or(unit);
+				if running_for < SLOW_JOB_THRESHOLD {
+					return JobLine {
+						text: description.to_string(),
+						slow: false,
+					};
+				}
+				let limit = info
+					.and_then(|info| info.timeout)
+					.map(format_timespan)
+					.unwrap_or_else(|| "no limit".to_string());
+				JobLine {
+					text: format!(
+						"A {verb} job is running for {description} ({} / {limit})",
+						format_timespan(running_for)
+					),
+					slow: true,
+				}
+			})
+			.collect()
+	}
+
+	fn job_lines<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.layout()
+				.child_alignment(Alignment::new(LX::Center, LY::Top))
+				.direction(LayoutDirection::TopToBottom)
+				.child_gap(6)
+				.end(),
+			|c| {
+				for line in &self.job_lines {
+					c.text(
+						&line.text,
+						TextConfig::new()
+							.color(if line.slow {
+								(0xFF, 0xCC, 0x00).into()
+							} else {
+								(0xFF, 0xFF, 0xFF, 0xA0).into()
+							})
+							.font_size(12)
+							.alignment(clay_layout::text::TextAlignment::Center)
+							.end(),
+					);
+				}
+			},
+		);
+	}
+
+	fn details<'clay, 'render>(
+		&'render self,
+		progress: &'render ProgressData,
+		slide_animation: f32,
+		c: &mut TibsClayScope<'clay, 'render>,
+	) where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.floating()
+				.attach_to(FloatingAttachToElement::Parent)
+				.attach_points(
+					FloatingAttachPointType::RightCenter,
+					FloatingAttachPointType::RightCenter,
+				)
+				// Slides in from the right edge
+				.offset(((1.0 - slide_animation) * (DETAILS_WIDTH + 20.0) - 20.0, 0.0).into())
+				.end()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.width(fixed!(DETAILS_WIDTH))
+				.height(Sizing::Percent(0.9))
+				.padding(Padding::all(16))
+				.child_gap(10)
+				.end()
+				.background_color((0x0E, 0x1A, 0x26, 0xE0).into())
+				.corner_radius()
+				.all(14.0)
+				.end(),
+			|c| {
+				c.text(
+					match self.transition {
+						Transition::Boot => "Boot details",
+						Transition::Shutdown => "Shutdown details",
+					},
+					TextConfig::new()
+						.color((0xFF, 0xFF, 0xFF).into())
+						.font_size(20)
+						.end(),
+				);
+				c.text(
+					// Esc exits in dev mode, F2 works everywhere
+					"Press F2 to hide",
+					TextConfig::new()
+						.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+						.font_size(12)
+						.end(),
+				);
+				let list_id = c.id("loading-details-list");
+				let scroll_offset = c.scroll_offset();
+				c.with(
+					Declaration::new()
+						.id(list_id)
+						.layout()
+						.direction(LayoutDirection::TopToBottom)
+						.width(grow!())
+						.height(grow!())
+						.child_gap(4)
+						.end()
+						.clip(false, true, scroll_offset),
+					|c| {
+						self.details_units(progress, c);
+						if let Some(kmsg) = &self.kernel_log {
+							Self::details_heading(c, "Kernel messages");
+							for message in kmsg.messages() {
+								c.text(
+									&message.text,
+									TextConfig::new()
+										.color(if message.level <= kernel_log::WARNING_LEVEL {
+											(0xFF, 0xCC, 0x00).into()
+										} else {
+											(0xFF, 0xFF, 0xFF, 0xA0).into()
+										})
+										.font_size(12)
+										.end(),
+								);
+							}
+						}
+					},
+				);
+			},
+		);
+	}
+
+	fn details_units<'clay, 'render>(
+		&'render self,
+		progress: &'render ProgressData,
+		c: &mut TibsClayScope<'clay, 'render>,
+	) where
+		'clay: 'render,
+	{
+		let groups = [
+			("Failed", ServiceState::Failed, (0xFF, 0xCC, 0x00)),
+			("Running", ServiceState::Loading, (0xFF, 0xFF, 0xFF)),
+			("Unknown result", ServiceState::Unknown, (0xA0, 0xA0, 0xA0)),
+			("Done", ServiceState::Loaded, (0x4C, 0xE3, 0xA2)),
+		];
+		for (heading, state, color) in groups {
+			let mut units = progress
+				.services
+				.iter()
+				.filter(|(_, &s)| s == state)
+				.map(|(unit, _)| unit)
+				.collect::<Vec<_>>();
+			if units.is_empty() {
+				continue;
+			}
+			units.sort();
+			Self::details_heading(c, heading);
+			for unit in units {
+				c.with(
+					Declaration::new()
+						.layout()
+						.direction(LayoutDirection::LeftToRight)
+						.child_gap(8)
+						.end(),
+					|c| {
+						c.text(
+							unit,
+							TextConfig::new().color(color.into()).font_size(12).end(),
+						);
+						if let Some(info) = progress.unit_info.get(unit) {
+							c.text(
+								&info.description,
+								TextConfig::new()
+									.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+									.font_size(12)
+									.end(),
+							);
+						}
+					},
+				);
+			}
+		}
+	}
+
+	fn details_heading<'clay, 'render>(c: &mut TibsClayScope<'clay, 'render>, heading: &'static str)
+	where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.layout()
+				.padding(Padding::new(0, 0, 8, 2))
+				.end(),
+			|c| {
+				c.text(
+					heading,
+					TextConfig::new()
+						.color((0xFF, 0xFF, 0xFF).into())
+						.font_size(14)
+						.end(),
+				);
 			},
 		);
 	}
@@ -340,3 +712,32 @@ impl LoadingScreen {
 		self.animations_state.get(id).copied().unwrap_or(0.0)
 	}
 }
+
+/// Formats like systemd does in its status messages, e.g. `1min 30s`.
+fn format_timespan(duration: Duration) -> String {
+	let secs = duration.as_secs();
+	let (hours, minutes, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+	let parts = [(hours, "h"), (minutes, "min"), (secs, "s")]
+		.into_iter()
+		.filter(|(value, _)| *value > 0)
+		.map(|(value, unit)| format!("{value}{unit}"))
+		.collect::<Vec<_>>();
+	if parts.is_empty() {
+		"0s".to_string()
+	} else {
+		parts.join(" ")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_format_timespan() {
+		assert_eq!(format_timespan(Duration::from_secs(90)), "1min 30s");
+		assert_eq!(format_timespan(Duration::from_millis(4500)), "4s");
+		assert_eq!(format_timespan(Duration::from_secs(3600 * 2 + 5)), "2h 5s");
+		assert_eq!(format_timespan(Duration::ZERO), "0s");
+	}
+}
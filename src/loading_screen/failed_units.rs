@@ -0,0 +1,697 @@
+use crate::admin_auth;
+use crate::pointer::Pointer;
+use crate::progress_watcher::{ProgressData, ServiceState};
+use crate::textbox::Textbox;
+use crate::unit_status::{self, UnitStatus};
+use crate::TibsClayScope;
+use clay_layout::{
+	elements::{FloatingAttachPointType, FloatingAttachToElement},
+	fixed, grow,
+	layout::{
+		Alignment, LayoutAlignmentX as LX, LayoutAlignmentY as LY, LayoutDirection, Padding, Sizing,
+	},
+	text::TextConfig,
+	Declaration,
+};
+use rustamarine::{keys, Rustamarine};
+use smol::channel;
+use std::thread;
+
+const JOURNAL_LINES: usize = 30;
+
+/// What we found out about the selected unit, fetched once when it's clicked.
+struct Inspection {
+	unit: String,
+	title: String,
+	summary: Vec<String>,
+	/// Only fetched once an administrator unlocked the inspector
+	journal: Vec<String>,
+	/// How the last restart went
+	message: Option<String>,
+	restarting: bool,
+}
+
+impl Inspection {
+	fn new(unit: &str) -> Self {
+		Self {
+			unit: unit.to_string(),
+			title: unit.to_string(),
+			summary: vec!["Loading...".to_string()],
+			journal: Vec::new(),
+			message: None,
+			restarting: false,
+		}
+	}
+
+	fn show(&mut self, details: Details) {
+		self.title = details.title;
+		self.summary = details.summary;
+		self.journal = details.journal;
+	}
+}
+
+/// What [`Worker`] fetches for [`Request::Inspect`].
+struct Details {
+	title: String,
+	summary: Vec<String>,
+	journal: Vec<String>,
+}
+
+impl Details {
+	fn fetch(unit: &str, journal: bool) -> Self {
+		let mut title = unit.to_string();
+		let mut summary = Vec::new();
+		match UnitStatus::of_unit(unit) {
+			Ok(status) => {
+				if !status.description.is_empty() {
+					title = format!("{} ({unit})", status.description);
+				}
+				summary.push(format!("State: {}", status.state));
+				summary.extend(status.result.map(|result| format!("Result: {result}")));
+				summary.extend(status.main_process);
+			}
+			Err(e) => {
+				log::warn!("Failed to get the status of {unit}: {e}");
+				summary.push(format!("Failed to get the status: {e}"));
+			}
+		}
+		let journal = if journal {
+			unit_status::journal_tail(unit, JOURNAL_LINES).unwrap_or_else(|e| {
+				log::warn!("Failed to read the journal of {unit}: {e}");
+				vec![format!("Failed to read the journal: {e}")]
+			})
+		} else {
+			Vec::new()
+		};
+		Self {
+			title,
+			summary,
+			journal,
+		}
+	}
+}
+
+/// Something for the [`Worker`] to do, answered with a [`Reply`].
+enum Request {
+	/// Fetch the status of a unit, and its journal if an administrator unlocked it
+	Inspect { unit: String, journal: bool },
+	/// Queue a restart of a unit
+	Restart(String),
+	/// Check that someone is an administrator
+	Authenticate { name: String, password: String },
+}
+
+impl Request {
+	fn run(self) -> Reply {
+		match self {
+			Request::Inspect { unit, journal } => Reply::Details {
+				details: Details::fetch(&unit, journal),
+				unit,
+			},
+			Request::Restart(unit) => {
+				let message = match unit_status::restart(&unit) {
+					Ok(()) => "Restart queued, the progress updates once it's done".to_string(),
+					Err(e) => {
+						log::error!("Failed to restart {unit}: {e}");
+						format!("Failed to restart: {e}")
+					}
+				};
+				Reply::Restarted { unit, message }
+			}
+			Request::Authenticate { name, password } => Reply::Authenticated(
+				admin_auth::authenticate(&name, &password)
+					.map(|()| name)
+					.map_err(|e| e.to_string()),
+			),
+		}
+	}
+}
+
+enum Reply {
+	Details {
+		unit: String,
+		details: Details,
+	},
+	Restarted {
+		unit: String,
+		message: String,
+	},
+	/// The name of the administrator, or why they couldn't log in
+	Authenticated(Result<String, String>),
+}
+
+/// Runs the requests of the inspector one after another on a thread of its own, since
+/// D-Bus, journalctl and PAM can take a while and the boot screen has to keep rendering.
+struct Worker {
+	request_tx: channel::Sender<Request>,
+	reply_rx: channel::Receiver<Reply>,
+}
+
+impl Worker {
+	fn spawn() -> Self {
+		let (request_tx, request_rx) = channel::unbounded::<Request>();
+		let (reply_tx, reply_rx) = channel::unbounded();
+		thread::spawn(move || {
+			// Stops once the inspector is dropped
+			while let Ok(request) = request_rx.recv_blocking() {
+				let mut requests = vec![request];
+				requests.extend(std::iter::from_fn(|| request_rx.try_recv().ok()));
+				// Only the last unit clicked is on screen, skip the ones clicked while we were busy
+				let latest_inspect = requests
+					.iter()
+					.rposition(|r| matches!(r, Request::Inspect { .. }));
+				for (i, request) in requests.into_iter().enumerate() {
+					if matches!(request, Request::Inspect { .. }) && Some(i) != latest_inspect {
+						continue;
+					}
+					if reply_tx.send_blocking(request.run()).is_err() {
+						return;
+					}
+				}
+			}
+		});
+		Self {
+			request_tx,
+			reply_rx,
+		}
+	}
+
+	fn send(&self, request: Request) {
+		let _ = self.request_tx.try_send(request);
+	}
+}
+
+/// Asks for the name and password of an administrator, before showing journals or
+/// restarting units to whoever is at the boot screen.
+struct AdminLogin {
+	name: Textbox,
+	password: Textbox,
+	/// Waiting for PAM
+	pending: bool,
+	error: Option<String>,
+}
+
+impl AdminLogin {
+	fn new() -> Self {
+		let mut password = Textbox::new("admin-password-input", true);
+		password.set_focused(false);
+		Self {
+			name: Textbox::new("admin-name-input", false),
+			password,
+			pending: false,
+			error: None,
+		}
+	}
+
+	/// Returns the name and password once they're submitted.
+	fn update(
+		&mut self,
+		c: &mut clay_layout::Clay,
+		rmar: &Rustamarine,
+		pointer: &Pointer,
+	) -> Option<(String, String)> {
+		self.name.disabled = self.pending;
+		self.password.disabled = self.pending;
+		if pointer.pressed {
+			if c.pointer_over(c.id("admin-name-input")) {
+				self.focus_password(false);
+			} else if c.pointer_over(c.id("admin-password-input")) {
+				self.focus_password(true);
+			}
+		}
+		self.name.update(rmar, pointer, c);
+		self.password.update(rmar, pointer, c);
+		if self.pending {
+			return None;
+		}
+		let enter = rmar.is_key_pressed(keys::KEY_Return) || rmar.is_key_pressed(keys::KEY_KP_Enter);
+		if enter && self.name.is_focused() {
+			self.focus_password(true);
+			return None;
+		}
+		let submitted = (enter && self.password.is_focused())
+			|| (pointer.pressed && c.pointer_over(c.id("admin-unlock")));
+		if !submitted || self.name.text().is_empty() {
+			return None;
+		}
+		self.pending = true;
+		self.error = None;
+		Some((
+			self.name.text().to_string(),
+			self.password.text().to_string(),
+		))
+	}
+
+	fn focus_password(&mut self, password: bool) {
+		self.name.set_focused(!password);
+		self.password.set_focused(password);
+	}
+
+	fn failed(&mut self, error: String) {
+		self.pending = false;
+		self.error = Some(error);
+		self.password.clear();
+		self.focus_password(true);
+	}
+}
+
+/// Lists the units that failed during boot, with their status and last journal lines.
+///
+/// The journal and restarting units are locked until someone in the admin group logs in,
+/// since anyone at the boot screen can open this. Closing it locks them again.
+pub struct FailedUnitInspector {
+	open: bool,
+	units: Vec<String>,
+	inspection: Option<Inspection>,
+	/// Started the first time the inspector opens
+	worker: Option<Worker>,
+	/// The administrator that unlocked the inspector
+	admin: Option<String>,
+	admin_login: AdminLogin,
+}
+
+impl FailedUnitInspector {
+	pub fn new() -> Self {
+		Self {
+			open: false,
+			units: Vec::new(),
+			inspection: None,
+			worker: None,
+			admin: None,
+			admin_login: AdminLogin::new(),
+		}
+	}
+
+	pub fn open(&mut self, progress: &ProgressData) {
+		self.open = true;
+		self.refresh_units(progress);
+		if self.inspection.is_none() {
+			if let Some(unit) = self.units.first().cloned() {
+				self.inspect(&unit);
+			}
+		}
+	}
+
+	pub fn is_open(&self) -> bool {
+		self.open
+	}
+
+	fn close(&mut self) {
+		self.open = false;
+		self.inspection = None;
+		self.admin = None;
+		self.admin_login = AdminLogin::new();
+	}
+
+	fn worker(&mut self) -> &Worker {
+		self.worker.get_or_insert_with(Worker::spawn)
+	}
+
+	fn inspect(&mut self, unit: &str) {
+		self.inspection = Some(Inspection::new(unit));
+		let journal = self.admin.is_some();
+		self.worker().send(Request::Inspect {
+			unit: unit.to_string(),
+			journal,
+		});
+	}
+
+	fn refresh_units(&mut self, progress: &ProgressData) {
+		self.units = progress
+			.services
+			.iter()
+			.filter(|(_, &state)| state == ServiceState::Failed)
+			.map(|(unit, _)| unit.clone())
+			.collect();
+		self.units.sort();
+	}
+
+	/// Take what the worker sent since the last frame.
+	fn poll(&mut self) {
+		let Some(worker) = &self.worker else {
+			return;
+		};
+		let mut unlocked = false;
+		while let Ok(reply) = worker.reply_rx.try_recv() {
+			match reply {
+				Reply::Details { unit, details } => {
+					if let Some(inspection) = self.inspection.as_mut().filter(|i| i.unit == unit) {
+						inspection.show(details);
+					}
+				}
+				Reply::Restarted { unit, message } => {
+					if let Some(inspection) = self.inspection.as_mut().filter(|i| i.unit == unit) {
+						inspection.message = Some(message);
+						inspection.restarting = false;
+					}
+				}
+				Reply::Authenticated(Ok(name)) => {
+					log::info!("{name} unlocked the failed units on the boot screen");
+					self.admin = Some(name);
+					self.admin_login = AdminLogin::new();
+					unlocked = true;
+				}
+				Reply::Authenticated(Err(e)) => {
+					log::warn!("Failed to unlock the failed units: {e}");
+					self.admin_login.failed(e);
+				}
+			}
+		}
+		// Fetch the journal that was left out before
+		if let Some(unit) = self
+			.inspection
+			.as_ref()
+			.filter(|_| unlocked)
+			.map(|i| i.unit.clone())
+		{
+			self.inspect(&unit);
+		}
+	}
+
+	pub fn update(
+		&mut self,
+		c: &mut clay_layout::Clay,
+		rmar: &Rustamarine,
+		pointer: &Pointer,
+		progress: &ProgressData,
+	) {
+		if !self.open {
+			return;
+		}
+		// Restarted units drop out of the list once they succeed
+		self.refresh_units(progress);
+		self.poll();
+		if self.admin.is_none() {
+			if let Some((name, password)) = self.admin_login.update(c, rmar, pointer) {
+				self.worker().send(Request::Authenticate { name, password });
+			}
+		}
+		if !pointer.pressed {
+			return;
+		}
+		if c.pointer_over(c.id("failed-units-close")) {
+			self.close();
+		} else if self.admin.is_some() && c.pointer_over(c.id("failed-unit-restart")) {
+			let Some(inspection) = self.inspection.as_mut().filter(|i| !i.restarting) else {
+				return;
+			};
+			log::info!(
+				"{} restarted {} from the boot screen",
+				self.admin.as_deref().unwrap_or_default(),
+				inspection.unit
+			);
+			inspection.restarting = true;
+			inspection.message = Some("Restarting...".to_string());
+			let unit = inspection.unit.clone();
+			self.worker().send(Request::Restart(unit));
+		} else if let Some(unit) = (0..self.units.len())
+			.find(|i| c.pointer_over(c.id(&format!("failed-unit-{i}"))))
+			.and_then(|i| self.units.get(i))
+			.cloned()
+		{
+			self.inspect(&unit);
+		}
+	}
+
+	pub fn render<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		if !self.open {
+			return;
+		}
+		c.with(
+			Declaration::new()
+				.floating()
+				.attach_to(FloatingAttachToElement::Parent)
+				.attach_points(
+					FloatingAttachPointType::CenterCenter,
+					FloatingAttachPointType::CenterCenter,
+				)
+				.end()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.padding(Padding::all(16))
+				.child_gap(12)
+				.width(fixed!(760.0))
+				.height(Sizing::Percent(0.8))
+				.end()
+				.background_color((0x0E, 0x1A, 0x26, 0xE0).into())
+				.corner_radius()
+				.all(14.0)
+				.end(),
+			|c| {
+				c.with(
+					Declaration::new()
+						.layout()
+						.direction(LayoutDirection::LeftToRight)
+						.child_alignment(Alignment::new(LX::Left, LY::Center))
+						.width(grow!())
+						.end(),
+					|c| {
+						c.text(
+							"Failed units",
+							TextConfig::new()
+								.color((0xFF, 0xFF, 0xFF).into())
+								.font_size(20)
+								.end(),
+						);
+						c.with(Declaration::new().layout().width(grow!()).end(), |_| {});
+						Self::render_button(c, "failed-units-close", "Close");
+					},
+				);
+				c.with(
+					Declaration::new()
+						.layout()
+						.direction(LayoutDirection::LeftToRight)
+						.child_gap(12)
+						.width(grow!())
+						.height(grow!())
+						.end(),
+					|c| {
+						self.render_unit_list(c);
+						if let Some(inspection) = &self.inspection {
+							self.render_inspection(c, inspection);
+						}
+					},
+				);
+			},
+		);
+	}
+
+	fn render_unit_list<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.child_gap(4)
+				.width(fixed!(220.0))
+				.end(),
+			|c| {
+				if self.units.is_empty() {
+					c.text(
+						"No failed units left",
+						TextConfig::new()
+							.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+							.font_size(14)
+							.end(),
+					);
+				}
+				for (i, unit) in self.units.iter().enumerate() {
+					let id = c.id(&format!("failed-unit-{i}"));
+					let selected = self
+						.inspection
+						.as_ref()
+						.is_some_and(|inspection| inspection.unit == *unit);
+					let mut item_decl = Declaration::new();
+					item_decl
+						.id(id)
+						.layout()
+						.width(grow!())
+						.padding(Padding::all(8))
+						.end()
+						.corner_radius()
+						.all(8.0)
+						.end();
+					if selected || c.pointer_over(id) {
+						item_decl.background_color((0x2E / 2, 0x3E / 2, 0x4E / 2, 0x30).into());
+					}
+					c.with(&item_decl, |c| {
+						c.text(
+							unit,
+							TextConfig::new()
+								.color((0xFF, 0xCC, 0x00).into())
+								.font_size(12)
+								.end(),
+						);
+					});
+				}
+			},
+		);
+	}
+
+	fn render_inspection<'clay, 'render>(
+		&'render self,
+		c: &mut TibsClayScope<'clay, 'render>,
+		inspection: &'render Inspection,
+	) where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.child_gap(6)
+				.width(grow!())
+				.height(grow!())
+				.end(),
+			|c| {
+				c.text(
+					&inspection.title,
+					TextConfig::new()
+						.color((0xFF, 0xFF, 0xFF).into())
+						.font_size(16)
+						.end(),
+				);
+				for line in &inspection.summary {
+					c.text(
+						line,
+						TextConfig::new()
+							.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+							.font_size(12)
+							.end(),
+					);
+				}
+				if self.admin.is_none() {
+					self.render_admin_login(c);
+					return;
+				}
+				Self::render_button(c, "failed-unit-restart", "Restart unit");
+				if let Some(message) = &inspection.message {
+					c.text(
+						message,
+						TextConfig::new()
+							.color((0xFF, 0xCC, 0x00).into())
+							.font_size(12)
+							.end(),
+					);
+				}
+				c.text(
+					"Journal",
+					TextConfig::new()
+						.color((0xFF, 0xFF, 0xFF).into())
+						.font_size(14)
+						.end(),
+				);
+				let journal_id = c.id("failed-unit-journal");
+				let scroll_offset = c.scroll_offset();
+				c.with(
+					Declaration::new()
+						.id(journal_id)
+						.layout()
+						.direction(LayoutDirection::TopToBottom)
+						.child_gap(2)
+						.width(grow!())
+						.height(grow!())
+						.end()
+						.clip(false, true, scroll_offset),
+					|c| {
+						for line in &inspection.journal {
+							c.text(
+								line,
+								TextConfig::new()
+									.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+									.font_size(12)
+									.end(),
+							);
+						}
+					},
+				);
+			},
+		);
+	}
+
+	fn render_admin_login<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		let login = &self.admin_login;
+		c.text(
+			"Log in as an administrator to see the journal and restart units",
+			TextConfig::new()
+				.color((0xFF, 0xFF, 0xFF).into())
+				.font_size(14)
+				.end(),
+		);
+		c.text(
+			"User name",
+			TextConfig::new()
+				.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+				.font_size(12)
+				.end(),
+		);
+		login.name.render(c);
+		c.text(
+			"Password",
+			TextConfig::new()
+				.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+				.font_size(12)
+				.end(),
+		);
+		login.password.render(c);
+		let label = if login.pending {
+			"Checking..."
+		} else {
+			"Unlock"
+		};
+		Self::render_button(c, "admin-unlock", label);
+		if let Some(error) = &login.error {
+			c.text(
+				error,
+				TextConfig::new()
+					.color((0xFF, 0xCC, 0x00).into())
+					.font_size(12)
+					.end(),
+			);
+		}
+	}
+
+	fn render_button<'clay, 'render>(
+		c: &mut TibsClayScope<'clay, 'render>,
+		id: &str,
+		label: &'render str,
+	) where
+		'clay: 'render,
+	{
+		let id = c.id(id);
+		let mut decl = Declaration::new();
+		decl
+			.id(id)
+			.layout()
+			.child_alignment(Alignment::new(LX::Center, LY::Center))
+			.padding(Padding::new(14, 14, 0, 0))
+			.height(fixed!(32.0))
+			.end()
+			.corner_radius()
+			.all(10.0)
+			.end();
+		if c.pointer_over(id) {
+			decl.background_color((0x2E, 0x3E, 0x4E, 0x80).into());
+		} else {
+			decl.background_color((0x2E, 0x3E, 0x4E, 0x30).into());
+		}
+		c.with(&decl, |c| {
+			c.text(
+				label,
+				TextConfig::new()
+					.color((0xFF, 0xFF, 0xFF).into())
+					.font_size(14)
+					.alignment(clay_layout::text::TextAlignment::Center)
+					.end(),
+			);
+		});
+	}
+}
@@ -1,5 +1,9 @@
+mod crash_panel;
+pub use crash_panel::*;
 mod manager;
 pub use manager::*;
+mod power_menu;
+pub use power_menu::*;
 mod screen;
 pub use screen::*;
 mod tty_switch_animation_manager;
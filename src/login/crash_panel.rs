@@ -0,0 +1,268 @@
+use std::time::Duration;
+
+use crate::pointer::Pointer;
+use crate::session_manager::{DesktopEnvironmentFile, Session};
+use crate::TibsClayScope;
+use clay_layout::{
+	fixed, grow,
+	layout::{Alignment, LayoutAlignmentX as LX, LayoutAlignmentY as LY, LayoutDirection, Padding},
+	text::TextConfig,
+	Declaration,
+};
+
+/// How many lines of the session log are shown
+const LOG_TAIL_LINES: usize = 8;
+
+/// Which session to start the next time the user logs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionChoice {
+	/// Index in [`crate::session_manager::SessionManager::get_desktop_environments_list`]
+	Desktop(usize),
+	Failsafe,
+}
+
+/// What went wrong with a session, formatted for display.
+pub struct CrashReport {
+	pub uid: u32,
+	/// `None` if the failsafe session crashed
+	session_index: Option<usize>,
+	title: String,
+	status: String,
+	log_tail: Vec<String>,
+	/// Names of the desktop environments that can be started instead
+	sessions: Vec<String>,
+}
+
+impl CrashReport {
+	pub fn new(uid: u32, session: &Session, desktop_environments: &[DesktopEnvironmentFile]) -> Self {
+		let name = session.session_file().name();
+		let status = match session.exit_reason() {
+			Some(reason) => format!("{reason} after {}", format_runtime(session.runtime())),
+			None => format!("stopped after {}", format_runtime(session.runtime())),
+		};
+		Self {
+			uid,
+			session_index: desktop_environments.iter().position(|d| d.name() == name),
+			title: format!("{name} crashed"),
+			status,
+			log_tail: session.log_tail(LOG_TAIL_LINES),
+			sessions: desktop_environments
+				.iter()
+				.map(|d| d.name().to_string())
+				.collect(),
+		}
+	}
+}
+
+/// Shown in place of the login prompt after a user's session crashed.
+///
+/// Picking an option doesn't start anything by itself, the user has to log in again
+/// so nobody can get into a crashed session without the password.
+pub struct CrashPanel {
+	report: Option<CrashReport>,
+	choosing_session: bool,
+}
+
+impl CrashPanel {
+	pub fn new() -> Self {
+		Self {
+			report: None,
+			choosing_session: false,
+		}
+	}
+
+	pub fn show(&mut self, report: CrashReport) {
+		self.report = Some(report);
+		self.choosing_session = false;
+	}
+
+	pub fn is_visible_for(&self, uid: u32) -> bool {
+		self.report.as_ref().is_some_and(|r| r.uid == uid)
+	}
+
+	/// Returns the session the user picked and its name, the panel closes once they pick one.
+	pub fn update(
+		&mut self,
+		c: &mut clay_layout::Clay,
+		pointer: &Pointer,
+		uid: u32,
+	) -> Option<(SessionChoice, String)> {
+		if !self.is_visible_for(uid) || !pointer.pressed {
+			return None;
+		}
+		let report = self.report.as_ref()?;
+		let choice = if c.pointer_over(c.id("crash-retry")) {
+			Some(
+				report
+					.session_index
+					.map(SessionChoice::Desktop)
+					.unwrap_or(SessionChoice::Failsafe),
+			)
+		} else if c.pointer_over(c.id("crash-failsafe")) {
+			Some(SessionChoice::Failsafe)
+		} else if c.pointer_over(c.id("crash-choose-session")) {
+			self.choosing_session = !self.choosing_session;
+			None
+		} else if self.choosing_session {
+			(0..report.sessions.len())
+				.find(|i| c.pointer_over(c.id(&format!("crash-session-{i}"))))
+				.map(SessionChoice::Desktop)
+		} else {
+			None
+		};
+		let name = match choice? {
+			SessionChoice::Desktop(i) => report.sessions.get(i).cloned()?,
+			SessionChoice::Failsafe => "Failsafe terminal".to_string(),
+		};
+		self.report = None;
+		Some((choice?, name))
+	}
+
+	pub fn render<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>, uid: u32)
+	where
+		'clay: 'render,
+	{
+		let Some(report) = self.report.as_ref().filter(|r| r.uid == uid) else {
+			return;
+		};
+		c.with(
+			Declaration::new()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.padding(Padding::all(16))
+				.child_gap(10)
+				.width(fixed!(520.0))
+				.end()
+				.background_color((0x0E, 0x1A, 0x26, 0xC0).into())
+				.corner_radius()
+				.all(14.0)
+				.end(),
+			|c| {
+				c.text(
+					&report.title,
+					TextConfig::new()
+						.color((0xFF, 0xCC, 0x00).into())
+						.font_size(20)
+						.end(),
+				);
+				c.text(
+					&report.status,
+					TextConfig::new()
+						.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+						.font_size(14)
+						.end(),
+				);
+				if !report.log_tail.is_empty() {
+					c.with(
+						Declaration::new()
+							.layout()
+							.direction(LayoutDirection::TopToBottom)
+							.padding(Padding::all(10))
+							.child_gap(2)
+							.width(grow!())
+							.end()
+							.background_color((0x00, 0x00, 0x00, 0x40).into())
+							.corner_radius()
+							.all(8.0)
+							.end(),
+						|c| {
+							for line in &report.log_tail {
+								c.text(
+									line,
+									TextConfig::new()
+										.color((0xFF, 0xFF, 0xFF, 0xC0).into())
+										.font_size(12)
+										.end(),
+								);
+							}
+						},
+					);
+				}
+				c.with(
+					Declaration::new()
+						.layout()
+						.direction(LayoutDirection::LeftToRight)
+						.child_gap(10)
+						.end(),
+					|c| {
+						Self::render_button(c, "crash-retry", "Retry");
+						if !report.sessions.is_empty() {
+							Self::render_button(c, "crash-choose-session", "Choose another session");
+						}
+						Self::render_button(c, "crash-failsafe", "Failsafe terminal");
+					},
+				);
+				if self.choosing_session {
+					c.with(
+						Declaration::new()
+							.layout()
+							.direction(LayoutDirection::TopToBottom)
+							.child_gap(4)
+							.width(grow!())
+							.end(),
+						|c| {
+							for (i, name) in report.sessions.iter().enumerate() {
+								Self::render_button(c, &format!("crash-session-{i}"), name);
+							}
+						},
+					);
+				}
+			},
+		);
+	}
+
+	fn render_button<'clay, 'render>(c: &mut TibsClayScope<'clay, 'render>, id: &str, label: &'render str)
+	where
+		'clay: 'render,
+	{
+		let id = c.id(id);
+		let mut decl = Declaration::new();
+		decl
+			.id(id)
+			.layout()
+			.child_alignment(Alignment::new(LX::Center, LY::Center))
+			.padding(Padding::new(14, 14, 0, 0))
+			.height(fixed!(36.0))
+			.end()
+			.corner_radius()
+			.all(10.0)
+			.end();
+		if c.pointer_over(id) {
+			decl.background_color((0x2E, 0x3E, 0x4E, 0x80).into());
+		} else {
+			decl.background_color((0x2E, 0x3E, 0x4E, 0x30).into());
+		}
+		c.with(&decl, |c| {
+			c.text(
+				label,
+				TextConfig::new()
+					.color((0xFF, 0xFF, 0xFF).into())
+					.font_size(14)
+					.alignment(clay_layout::text::TextAlignment::Center)
+					.end(),
+			);
+		});
+	}
+}
+
+/// Format a duration like `1h 02m`, `3m 05s` or `12s`.
+fn format_runtime(duration: Duration) -> String {
+	let seconds = duration.as_secs();
+	match (seconds / 3600, seconds / 60 % 60, seconds % 60) {
+		(0, 0, s) => format!("{s}s"),
+		(0, m, s) => format!("{m}m {s:02}s"),
+		(h, m, _) => format!("{h}h {m:02}m"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_format_runtime() {
+		assert_eq!(format_runtime(Duration::from_secs(12)), "12s");
+		assert_eq!(format_runtime(Duration::from_secs(185)), "3m 05s");
+		assert_eq!(format_runtime(Duration::from_secs(3720)), "1h 02m");
+	}
+}
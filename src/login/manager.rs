@@ -13,11 +13,14 @@ pub enum LoginState {
 }
 pub struct LoginManager {
 	login_state_map: Arc<Mutex<HashMap<String, LoginState>>>,
+	/// Users that authenticated since the last [`LoginManager::take_authenticated`] call
+	authenticated: Arc<Mutex<Vec<(String, u32)>>>,
 }
 impl Clone for LoginManager {
 	fn clone(&self) -> Self {
 		Self {
 			login_state_map: Arc::clone(&self.login_state_map),
+			authenticated: Arc::clone(&self.authenticated),
 		}
 	}
 }
@@ -25,6 +28,7 @@ impl LoginManager {
 	pub fn new() -> Self {
 		Self {
 			login_state_map: Default::default(),
+			authenticated: Default::default(),
 		}
 	}
 
@@ -42,6 +46,7 @@ impl LoginManager {
 				Some(LoginState::Logging) => return false,
 				_ => {
 					let login_map = Arc::clone(&self.login_state_map);
+					let authenticated = Arc::clone(&self.authenticated);
 					login_map_lock.insert(name.clone(), LoginState::Logging);
 					std::thread::spawn(move || {
 						let error = || {
@@ -75,6 +80,9 @@ impl LoginManager {
 
 						println!("[INFO] Logged into {uid}");
 						login_map_lock.insert(name.clone(), LoginState::Authenticated(uid));
+						if let Ok(mut authenticated) = authenticated.lock() {
+							authenticated.push((name.clone(), uid));
+						}
 					});
 				}
 			}
@@ -82,6 +90,12 @@ impl LoginManager {
 		true
 	}
 
+	/// Get the next user that finished authenticating, to start their session.
+	pub fn take_authenticated(&self) -> Option<(String, u32)> {
+		let mut authenticated = self.authenticated.lock().ok()?;
+		(!authenticated.is_empty()).then(|| authenticated.remove(0))
+	}
+
 	pub fn get_current_login_state(&self, name: impl Into<String>) -> Option<LoginState> {
 		self.login_state_map.lock().ok()?.get(&name.into()).copied()
 	}
@@ -0,0 +1,518 @@
+use crate::pointer::Pointer;
+use crate::power::{self, LogindSession, PowerAction};
+use crate::session_manager::SessionManager;
+use crate::TibsClayScope;
+use clay_layout::{
+	elements::{FloatingAttachPointType, FloatingAttachToElement},
+	fixed, grow,
+	layout::{Alignment, LayoutAlignmentX as LX, LayoutAlignmentY as LY, LayoutDirection, Padding},
+	text::TextConfig,
+	Declaration,
+};
+use smol::channel;
+use std::thread;
+
+/// Everything that will be affected by an action, shown before doing it.
+struct Confirmation {
+	action: PowerAction,
+	title: String,
+	/// Our own sessions, with the tty they run on
+	own_sessions: Vec<(String, u16)>,
+	sessions: Vec<String>,
+	inhibitors: Vec<String>,
+	error: Option<String>,
+	/// The logind sessions and inhibitors, until the worker thread fetched them
+	details_rx: Option<channel::Receiver<Details>>,
+	/// How the action went, while logind is still working on it
+	perform_rx: Option<channel::Receiver<zbus::Result<()>>>,
+}
+
+impl Confirmation {
+	fn new(action: PowerAction, session_manager: &SessionManager) -> Self {
+		let own_sessions = session_manager
+			.running_sessions()
+			.map(|s| {
+				let name = uzers::get_user_by_uid(s.user_id())
+					.map(|u| u.name().to_string_lossy().into_owned())
+					.unwrap_or_else(|| s.user_id().to_string());
+				(name, s.tty_number())
+			})
+			.collect::<Vec<_>>();
+		let mut confirmation = Self {
+			action,
+			title: format!("{}?", action.label()),
+			own_sessions,
+			sessions: Vec::new(),
+			inhibitors: Vec::new(),
+			error: None,
+			details_rx: Some(in_background(move || Details::fetch(action))),
+			perform_rx: None,
+		};
+		confirmation.show_sessions(&[]);
+		confirmation
+	}
+
+	fn show_sessions(&mut self, logind_sessions: &[LogindSession]) {
+		let mut sessions = self
+			.own_sessions
+			.iter()
+			.map(|(user, tty)| format!("{user} on tty{tty}"))
+			.collect::<Vec<_>>();
+		for session in logind_sessions {
+			// Our own sessions may be registered with logind too, other sessions of the
+			// same user like ssh logins still have to be listed
+			if session.vt != 0
+				&& self
+					.own_sessions
+					.iter()
+					.any(|(_, tty)| u32::from(*tty) == session.vt)
+			{
+				continue;
+			}
+			if session.seat.is_empty() {
+				sessions.push(format!("{} in session {}", session.user, session.id));
+			} else {
+				sessions.push(format!(
+					"{} in session {} on {}",
+					session.user, session.id, session.seat
+				));
+			}
+		}
+		self.sessions = sessions;
+	}
+
+	fn perform(&mut self) {
+		if self.perform_rx.is_some() {
+			return;
+		}
+		log::info!("{} requested from the greeter", self.action.label());
+		let action = self.action;
+		self.error = None;
+		self.perform_rx = Some(in_background(move || power::perform(action)));
+	}
+
+	/// Take what the worker threads sent since the last frame, `true` once the action went through.
+	fn poll(&mut self) -> bool {
+		if let Some(details_rx) = &self.details_rx {
+			match details_rx.try_recv() {
+				Ok(details) => {
+					self.show_sessions(&details.sessions);
+					self.inhibitors = details.inhibitors;
+					self.details_rx = None;
+				}
+				Err(channel::TryRecvError::Closed) => self.details_rx = None,
+				Err(channel::TryRecvError::Empty) => {}
+			}
+		}
+		if let Some(perform_rx) = &self.perform_rx {
+			match perform_rx.try_recv() {
+				Ok(Ok(())) => return true,
+				Ok(Err(e)) => {
+					log::error!("Failed to {}: {e}", self.action.label().to_lowercase());
+					self.error = Some(e.to_string());
+					self.perform_rx = None;
+				}
+				Err(channel::TryRecvError::Closed) => self.perform_rx = None,
+				Err(channel::TryRecvError::Empty) => {}
+			}
+		}
+		false
+	}
+}
+
+/// What [`Confirmation::new`] fetches in the background.
+struct Details {
+	sessions: Vec<LogindSession>,
+	inhibitors: Vec<String>,
+}
+
+impl Details {
+	fn fetch(action: PowerAction) -> Self {
+		let sessions = power::logind_sessions().unwrap_or_else(|e| {
+			log::warn!("Failed to list logind sessions: {e}");
+			Vec::new()
+		});
+		let inhibitors = power::inhibitors(action)
+			.inspect_err(|e| log::warn!("Failed to list inhibitors: {e}"))
+			.unwrap_or_default()
+			.into_iter()
+			.map(|i| {
+				let blocking = if i.blocking { " (blocking)" } else { "" };
+				format!("{}: {}{blocking}", i.who, i.why)
+			})
+			.collect();
+		Self {
+			sessions,
+			inhibitors,
+		}
+	}
+}
+
+/// Run a logind call on its own thread, since D-Bus can take a while and the greeter
+/// has to keep rendering.
+fn in_background<T: Send + 'static>(
+	work: impl FnOnce() -> T + Send + 'static,
+) -> channel::Receiver<T> {
+	let (tx, rx) = channel::bounded(1);
+	thread::spawn(move || {
+		let _ = tx.send_blocking(work());
+	});
+	rx
+}
+
+/// Power button in the corner of the greeter, with a confirmation step before anything happens.
+pub struct PowerMenu {
+	open: bool,
+	/// Queried from logind every time the menu opens
+	actions: Vec<PowerAction>,
+	/// The available actions, until the worker thread asked logind
+	actions_rx: Option<channel::Receiver<Vec<PowerAction>>>,
+	confirmation: Option<Confirmation>,
+}
+
+impl PowerMenu {
+	pub fn new() -> Self {
+		Self {
+			open: false,
+			actions: Vec::new(),
+			actions_rx: None,
+			confirmation: None,
+		}
+	}
+
+	pub fn update(
+		&mut self,
+		c: &mut clay_layout::Clay,
+		pointer: &Pointer,
+		session_manager: &SessionManager,
+	) {
+		self.poll();
+		if !pointer.pressed {
+			return;
+		}
+		if let Some(confirmation) = &mut self.confirmation {
+			// logind may already be shutting down, there's nothing left to cancel
+			if confirmation.perform_rx.is_some() {
+				return;
+			}
+			if c.pointer_over(c.id("power-cancel")) {
+				self.confirmation = None;
+			} else if c.pointer_over(c.id("power-confirm")) {
+				confirmation.perform();
+			}
+			return;
+		}
+		if c.pointer_over(c.id("power-button")) {
+			self.open = !self.open;
+			if self.open && self.actions_rx.is_none() {
+				self.actions.clear();
+				self.actions_rx = Some(in_background(|| {
+					power::available_actions().unwrap_or_else(|e| {
+						log::warn!("Failed to ask logind which power actions are available: {e}");
+						Vec::new()
+					})
+				}));
+			}
+			return;
+		}
+		if self.open {
+			if let Some(&action) = (0..self.actions.len())
+				.find(|i| c.pointer_over(c.id(&format!("power-action-{i}"))))
+				.and_then(|i| self.actions.get(i))
+			{
+				self.confirmation = Some(Confirmation::new(action, session_manager));
+			}
+			self.open = false;
+		}
+	}
+
+	fn poll(&mut self) {
+		if let Some(actions_rx) = &self.actions_rx {
+			match actions_rx.try_recv() {
+				Ok(actions) => {
+					self.actions = actions;
+					self.actions_rx = None;
+				}
+				Err(channel::TryRecvError::Closed) => self.actions_rx = None,
+				Err(channel::TryRecvError::Empty) => {}
+			}
+		}
+		if let Some(confirmation) = &mut self.confirmation {
+			// Suspend and hibernate come back to the greeter, which shouldn't still be asking
+			if confirmation.poll() {
+				self.confirmation = None;
+				self.open = false;
+			}
+		}
+	}
+
+	pub fn render<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.floating()
+				.attach_to(FloatingAttachToElement::Parent)
+				.attach_points(
+					FloatingAttachPointType::RightBottom,
+					FloatingAttachPointType::RightBottom,
+				)
+				.offset((-55.0, -55.0).into())
+				.end()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.child_alignment(Alignment::new(LX::Right, LY::Bottom))
+				.child_gap(8)
+				.end(),
+			|c| {
+				if self.open {
+					self.render_actions(c);
+				}
+				let id = c.id("power-button");
+				let mut button_decl = Declaration::new();
+				button_decl
+					.id(id)
+					.layout()
+					.child_alignment(Alignment::new(LX::Center, LY::Center))
+					.width(fixed!(40.0))
+					.height(fixed!(40.0))
+					.end()
+					.corner_radius()
+					.all(10.0)
+					.end();
+				if c.pointer_over(id) || self.open {
+					button_decl.background_color((0x2E / 2, 0x3E / 2, 0x4E / 2, 0x30).into());
+				} else {
+					button_decl.background_color((0x0E, 0x1A, 0x26, 0x30).into());
+				}
+				c.with(&button_decl, |c| {
+					c.text(
+						PowerAction::PowerOff.icon(),
+						TextConfig::new()
+							.color((0xFF, 0xFF, 0xFF).into())
+							.font_size(20)
+							.alignment(clay_layout::text::TextAlignment::Center)
+							.end(),
+					);
+				});
+			},
+		);
+		if let Some(confirmation) = &self.confirmation {
+			Self::render_confirmation(c, confirmation);
+		}
+	}
+
+	fn render_actions<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.padding(Padding::all(5))
+				.end()
+				.background_color((0x0E, 0x1A, 0x26, 0xC0).into())
+				.corner_radius()
+				.all(10.0)
+				.end(),
+			|c| {
+				if self.actions.is_empty() {
+					let text = if self.actions_rx.is_some() {
+						"Loading..."
+					} else {
+						"No power actions available"
+					};
+					c.with(
+						Declaration::new().layout().padding(Padding::all(10)).end(),
+						|c| {
+							c.text(
+								text,
+								TextConfig::new()
+									.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+									.font_size(14)
+									.end(),
+							);
+						},
+					);
+				}
+				for (i, action) in self.actions.iter().enumerate() {
+					let id = c.id(&format!("power-action-{i}"));
+					let mut item_decl = Declaration::new();
+					item_decl
+						.id(id)
+						.layout()
+						.direction(LayoutDirection::LeftToRight)
+						.child_gap(12)
+						.width(grow!())
+						.padding(Padding::all(10))
+						.end()
+						.corner_radius()
+						.all(8.0)
+						.end();
+					if c.pointer_over(id) {
+						item_decl.background_color((0x2E / 2, 0x3E / 2, 0x4E / 2, 0x30).into());
+					}
+					c.with(&item_decl, |c| {
+						c.text(
+							action.icon(),
+							TextConfig::new()
+								.color((0xFF, 0xFF, 0xFF).into())
+								.font_size(14)
+								.end(),
+						);
+						c.text(
+							action.label(),
+							TextConfig::new()
+								.color((0xFF, 0xFF, 0xFF).into())
+								.font_size(14)
+								.end(),
+						);
+					});
+				}
+			},
+		);
+	}
+
+	fn render_confirmation<'clay, 'render>(
+		c: &mut TibsClayScope<'clay, 'render>,
+		confirmation: &'render Confirmation,
+	) where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.floating()
+				.attach_to(FloatingAttachToElement::Parent)
+				.attach_points(
+					FloatingAttachPointType::CenterCenter,
+					FloatingAttachPointType::CenterCenter,
+				)
+				.end()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.padding(Padding::all(16))
+				.child_gap(10)
+				.width(fixed!(420.0))
+				.end()
+				.background_color((0x0E, 0x1A, 0x26, 0xE0).into())
+				.corner_radius()
+				.all(14.0)
+				.end(),
+			|c| {
+				c.text(
+					&confirmation.title,
+					TextConfig::new()
+						.color((0xFF, 0xFF, 0xFF).into())
+						.font_size(20)
+						.end(),
+				);
+				Self::render_list(c, "These sessions will be closed:", &confirmation.sessions);
+				Self::render_list(c, "Programs holding it back:", &confirmation.inhibitors);
+				if confirmation.details_rx.is_some() {
+					c.text(
+						"Looking for other sessions...",
+						TextConfig::new()
+							.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+							.font_size(14)
+							.end(),
+					);
+				}
+				if confirmation.perform_rx.is_some() {
+					c.text(
+						"Waiting for logind...",
+						TextConfig::new()
+							.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+							.font_size(14)
+							.end(),
+					);
+				}
+				if let Some(error) = &confirmation.error {
+					c.text(
+						error,
+						TextConfig::new()
+							.color((0xFF, 0xCC, 0x00).into())
+							.font_size(14)
+							.end(),
+					);
+				}
+				c.with(
+					Declaration::new()
+						.layout()
+						.direction(LayoutDirection::LeftToRight)
+						.child_gap(10)
+						.end(),
+					|c| {
+						Self::render_button(c, "power-cancel", "Cancel");
+						Self::render_button(c, "power-confirm", confirmation.action.label());
+					},
+				);
+			},
+		);
+	}
+
+	fn render_list<'clay, 'render>(
+		c: &mut TibsClayScope<'clay, 'render>,
+		heading: &'static str,
+		items: &'render [String],
+	) where
+		'clay: 'render,
+	{
+		if items.is_empty() {
+			return;
+		}
+		c.text(
+			heading,
+			TextConfig::new()
+				.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+				.font_size(14)
+				.end(),
+		);
+		for item in items {
+			c.text(
+				item,
+				TextConfig::new()
+					.color((0xFF, 0xFF, 0xFF).into())
+					.font_size(14)
+					.end(),
+			);
+		}
+	}
+
+	fn render_button<'clay, 'render>(
+		c: &mut TibsClayScope<'clay, 'render>,
+		id: &str,
+		label: &'render str,
+	) where
+		'clay: 'render,
+	{
+		let id = c.id(id);
+		let mut decl = Declaration::new();
+		decl
+			.id(id)
+			.layout()
+			.child_alignment(Alignment::new(LX::Center, LY::Center))
+			.padding(Padding::new(14, 14, 0, 0))
+			.height(fixed!(36.0))
+			.end()
+			.corner_radius()
+			.all(10.0)
+			.end();
+		if c.pointer_over(id) {
+			decl.background_color((0x2E, 0x3E, 0x4E, 0x80).into());
+		} else {
+			decl.background_color((0x2E, 0x3E, 0x4E, 0x30).into());
+		}
+		c.with(&decl, |c| {
+			c.text(
+				label,
+				TextConfig::new()
+					.color((0xFF, 0xFF, 0xFF).into())
+					.font_size(14)
+					.alignment(clay_layout::text::TextAlignment::Center)
+					.end(),
+			);
+		});
+	}
+}
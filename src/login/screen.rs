@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use super::{LoginManager, LoginState};
+use crate::login_records::LastLogin;
+use super::{CrashPanel, CrashReport, LoginManager, LoginState, PowerMenu, SessionChoice};
+use crate::on_screen_keyboard::{OnScreenKeyboard, OnScreenKeyboardEvent};
+use crate::pointer::Pointer;
+use crate::session_manager::SessionManager;
 use crate::textbox::Textbox;
 use crate::TibsClayScope;
 use crate::{custom_elements::CustomElements, skia_image_asset::SkiaImageAsset};
@@ -27,8 +31,22 @@ pub struct LoginScreen {
 	login_icon: Image,
 	eye_icon: Image,
 	eye_off_icon: Image,
+	alert_icon: Image,
 	avatars: HashMap<u32, Image>,
 	password_input: Textbox,
+	/// (short name, description) of every keyboard layout, rebuilt when the layout count changes
+	layouts: Vec<(String, String)>,
+	active_layout: u32,
+	layout_menu_open: bool,
+	osk: OnScreenKeyboard,
+	crash_panel: CrashPanel,
+	power_menu: PowerMenu,
+	/// Session picked on the crash panel for a user, with its name
+	session_choice: Option<(u32, SessionChoice, String)>,
+	/// Last login of the selected user, from lastlog
+	last_login: Option<String>,
+	caps_lock: bool,
+	num_lock: bool,
 }
 
 fn is_user_uid(uid: u32) -> bool {
@@ -56,6 +74,9 @@ impl LoginScreen {
 		let SkiaImageAsset(eye_off_icon) = assets
 			.load_owned("icons.eye-off")
 			.expect("Failed to load icons.eye-off");
+		let SkiaImageAsset(alert_icon) = assets
+			.load_owned("icons.triangle-alert")
+			.expect("Failed to load icons.triangle-alert");
 
 		let user_list = unsafe { all_users() }
 			.filter(|u| is_user_uid(u.uid()) && !u.shell().ends_with("nologin"))
@@ -75,13 +96,26 @@ impl LoginScreen {
 			password_input: Textbox::new("login-input", true),
 			eye_icon,
 			eye_off_icon,
+			alert_icon,
+			layouts: Vec::new(),
+			active_layout: 0,
+			layout_menu_open: false,
+			osk: OnScreenKeyboard::new(),
+			crash_panel: CrashPanel::new(),
+			power_menu: PowerMenu::new(),
+			session_choice: None,
+			last_login: LastLogin::of_user(selected_user).map(|l| l.describe()),
+			caps_lock: false,
+			num_lock: false,
 		}
 	}
 	pub fn update<'clay, 'render>(
 		&'render mut self,
 		c: &mut clay_layout::Clay,
 		rmar: &Rustamarine,
+		pointer: &Pointer,
 		login_manager: &mut LoginManager,
+		session_manager: &SessionManager,
 	) where
 		'clay: 'render,
 	{
@@ -93,13 +127,24 @@ impl LoginScreen {
 			let n = selected.name().to_str().unwrap();
 			if self.selected_username != n {
 				self.selected_username = n.to_string();
+				self.refresh_last_login();
 			}
 		}
-		self.password_input.update(rmar, &mut *c);
-		if c.pointer_over(c.id("show-password")) && rmar.is_mouse_button_pressed(0) {
+		self.update_layout_switcher(c, rmar, pointer);
+		self.power_menu.update(c, pointer, session_manager);
+		if let Some((choice, name)) = self.crash_panel.update(c, pointer, self.selected_user) {
+			let hint = format!("Log in again to start {name}");
+			self.session_choice = Some((self.selected_user, choice, hint));
+		}
+		self.caps_lock = rmar.is_caps_lock_active();
+		self.num_lock = rmar.is_num_lock_active();
+		self.password_input.warning = self.caps_lock;
+		self.password_input.update(rmar, pointer, &mut *c);
+		if c.pointer_over(c.id("show-password")) && pointer.pressed {
 			self.password_input.hide_input = !self.password_input.hide_input
 		}
-		if ((c.pointer_over(c.id("login-button")) && rmar.is_mouse_button_pressed(0)) || (self.password_input.is_focused() && (rmar.is_key_pressed(KEY_Return)||rmar.is_key_pressed(KEY_KP_Enter)))) && !self.password_input.disabled {
+		let osk_enter = self.update_on_screen_keyboard(c, pointer);
+		if ((c.pointer_over(c.id("login-button")) && pointer.pressed) || osk_enter || (self.password_input.is_focused() && (rmar.is_key_pressed(KEY_Return)||rmar.is_key_pressed(KEY_KP_Enter)))) && !self.password_input.disabled {
 			login_manager.start_login(&self.selected_username, self.password_input.text(), true);
 		}
 		self.password_input.disabled = matches!(
@@ -107,18 +152,263 @@ impl LoginScreen {
 			Some(LoginState::Logging)
 		);
 	}
+	/// Show what went wrong with a user's session, instead of going straight back to the login prompt.
+	pub fn show_crash_report(&mut self, report: CrashReport) {
+		if let Some(user) = self.user_list.iter().find(|u| u.uid() == report.uid) {
+			self.selected_user = user.uid();
+		}
+		self.crash_panel.show(report);
+	}
+	/// Read the last login of the selected user again, after a session ended.
+	pub fn refresh_last_login(&mut self) {
+		self.last_login = LastLogin::of_user(self.selected_user).map(|l| l.describe());
+	}
+	/// Take the session the user picked on the crash panel, once they logged in again.
+	pub fn take_session_choice(&mut self, uid: u32) -> Option<SessionChoice> {
+		if !self.session_choice.as_ref().is_some_and(|(u, _, _)| *u == uid) {
+			return None;
+		}
+		self.session_choice.take().map(|(_, choice, _)| choice)
+	}
+	/// Returns whether Enter was pressed on the on-screen keyboard.
+	fn update_on_screen_keyboard(&mut self, c: &mut clay_layout::Clay, pointer: &Pointer) -> bool {
+		if pointer.pressed {
+			if c.pointer_over(c.id("osk-toggle")) {
+				self.osk.visible = !self.osk.visible;
+			} else if pointer.is_touch && c.pointer_over(c.id("login-input")) {
+				self.osk.visible = true;
+			}
+		}
+		match self.osk.update(c, pointer) {
+			Some(OnScreenKeyboardEvent::Text(text)) if !self.password_input.disabled => {
+				self.password_input.insert_text(&text, c);
+			}
+			Some(OnScreenKeyboardEvent::Backspace) if !self.password_input.disabled => {
+				self.password_input.backspace(c);
+			}
+			Some(OnScreenKeyboardEvent::Enter) => return true,
+			_ => {}
+		}
+		false
+	}
+	fn update_layout_switcher(&mut self, c: &mut clay_layout::Clay, rmar: &Rustamarine, pointer: &Pointer) {
+		// tibs only sets the keymap once at startup, so the names are fetched when the
+		// layouts first show up instead of on every frame
+		if self.layouts.len() != rmar.get_layout_count() as usize {
+			let keymap = rmar.get_keymap();
+			let short_names = keymap.layouts();
+			self.layouts = (0..rmar.get_layout_count())
+				.map(|i| {
+					let short_name = short_names.get(i as usize).copied().unwrap_or("??");
+					let description = rmar.get_layout_name(i).unwrap_or_else(|| short_name.to_string());
+					(short_name.to_string(), description)
+				})
+				.collect();
+		}
+		self.active_layout = rmar.get_active_layout();
+
+		if !pointer.pressed {
+			return;
+		}
+		if c.pointer_over(c.id("layout-switcher")) {
+			self.layout_menu_open = !self.layout_menu_open && self.layouts.len() > 1;
+			return;
+		}
+		if self.layout_menu_open {
+			if let Some(i) =
+				(0..self.layouts.len()).find(|i| c.pointer_over(c.id(&format!("layout-item-{i}"))))
+			{
+				rmar.set_active_layout(i as u32);
+				self.active_layout = i as u32;
+			}
+			self.layout_menu_open = false;
+		}
+	}
 	pub fn render<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>,
-	login_manager: &LoginManager)
+	login_manager: &LoginManager, session_manager: &SessionManager)
 	where
 		'clay: 'render,
 	{
-		self.render_user_list(c);
-		self.render_selected_user(c, login_manager);
+		self.render_user_list(c, session_manager);
+		self.render_selected_user(c, login_manager, session_manager);
+		self.render_layout_switcher(c);
+		self.render_on_screen_keyboard(c);
+		self.power_menu.render(c);
+	}
+
+	fn render_on_screen_keyboard<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.floating()
+				.attach_to(FloatingAttachToElement::Parent)
+				.attach_points(
+					FloatingAttachPointType::LeftTop,
+					FloatingAttachPointType::LeftTop,
+				)
+				.offset((55.0, 55.0).into())
+				.end(),
+			|c| {
+				let id = c.id("osk-toggle");
+				let mut button_decl = Declaration::new();
+				button_decl
+					.id(id)
+					.layout()
+					.child_alignment(Alignment::new(LX::Center, LY::Center))
+					.width(fixed!(40.0))
+					.height(fixed!(40.0))
+					.end()
+					.corner_radius()
+					.all(10.0)
+					.end();
+				if c.pointer_over(id) || self.osk.visible {
+					button_decl.background_color((0x2E / 2, 0x3E / 2, 0x4E / 2, 0x30).into());
+				} else {
+					button_decl.background_color((0x0E, 0x1A, 0x26, 0x30).into());
+				}
+				c.with(&button_decl, |c| {
+					c.text(
+						"\u{f11c}",
+						TextConfig::new()
+							.color((0xFF, 0xFF, 0xFF).into())
+							.font_size(20)
+							.alignment(clay_layout::text::TextAlignment::Center)
+							.end(),
+					);
+				});
+			},
+		);
+		if !self.osk.visible {
+			return;
+		}
+		c.with(
+			Declaration::new()
+				.floating()
+				.attach_to(FloatingAttachToElement::Parent)
+				.attach_points(
+					FloatingAttachPointType::CenterBottom,
+					FloatingAttachPointType::CenterBottom,
+				)
+				.offset((0.0, -30.0).into())
+				.end(),
+			|c| self.osk.render(c),
+		);
 	}
 
-	fn render_user_list<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	fn render_layout_switcher<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
 	where
 		'clay: 'render,
+	{
+		let Some((short_name, _)) = self.layouts.get(self.active_layout as usize) else {
+			return;
+		};
+		c.with(
+			Declaration::new()
+				.floating()
+				.attach_to(FloatingAttachToElement::Parent)
+				.attach_points(
+					FloatingAttachPointType::RightTop,
+					FloatingAttachPointType::RightTop,
+				)
+				.offset((-55.0, 55.0).into())
+				.end()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.child_alignment(Alignment::new(LX::Right, LY::Top))
+				.child_gap(8)
+				.end(),
+			|c| {
+				let id = c.id("layout-switcher");
+				let mut button_decl = Declaration::new();
+				button_decl
+					.id(id)
+					.layout()
+					.child_alignment(Alignment::new(LX::Center, LY::Center))
+					.padding(Padding::new(14, 14, 0, 0))
+					.height(fixed!(40.0))
+					.end()
+					.corner_radius()
+					.all(10.0)
+					.end();
+				if c.pointer_over(id) || self.layout_menu_open {
+					button_decl.background_color((0x2E / 2, 0x3E / 2, 0x4E / 2, 0x30).into());
+				} else {
+					button_decl.background_color((0x0E, 0x1A, 0x26, 0x30).into());
+				}
+				c.with(&button_decl, |c| {
+					c.text(
+						short_name,
+						TextConfig::new()
+							.color((0xFF, 0xFF, 0xFF).into())
+							.font_size(16)
+							.alignment(clay_layout::text::TextAlignment::Center)
+							.end(),
+					);
+				});
+				if !self.layout_menu_open {
+					return;
+				}
+				c.with(
+					Declaration::new()
+						.layout()
+						.direction(LayoutDirection::TopToBottom)
+						.padding(Padding::all(5))
+						.end()
+						.background_color((0x0E, 0x1A, 0x26, 0xC0).into())
+						.corner_radius()
+						.all(10.0)
+						.end(),
+					|c| {
+						for (i, (short_name, description)) in self.layouts.iter().enumerate() {
+							let id = c.id(&format!("layout-item-{i}"));
+							let mut item_decl = Declaration::new();
+							item_decl
+								.id(id)
+								.layout()
+								.direction(LayoutDirection::LeftToRight)
+								.child_gap(12)
+								.width(grow!())
+								.padding(Padding::all(10))
+								.end()
+								.corner_radius()
+								.all(8.0)
+								.end();
+							if c.pointer_over(id) {
+								item_decl.background_color((0x2E / 2, 0x3E / 2, 0x4E / 2, 0x30).into());
+							} else if i as u32 == self.active_layout {
+								item_decl.background_color((0x2E, 0x3E, 0x4E, 0x30).into());
+							}
+							c.with(&item_decl, |c| {
+								c.text(
+									short_name,
+									TextConfig::new()
+										.color((0xFF, 0xFF, 0xFF).into())
+										.font_size(14)
+										.end(),
+								);
+								c.text(
+									description,
+									TextConfig::new()
+										.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+										.font_size(14)
+										.end(),
+								);
+							});
+						}
+					},
+				);
+			},
+		);
+	}
+
+	fn render_user_list<'clay, 'render>(
+		&'render self,
+		c: &mut TibsClayScope<'clay, 'render>,
+		session_manager: &SessionManager,
+	) where
+		'clay: 'render,
 	{
 		c.with(
 			Declaration::new()
@@ -137,7 +427,8 @@ impl LoginScreen {
 			|c| {
 				for user in &self.user_list {
 					let is_selected = user.uid() == self.selected_user;
-					self.render_user_item(c, user, is_selected);
+					let has_session = session_manager.is_running(user.uid());
+					self.render_user_item(c, user, is_selected, has_session);
 				}
 			},
 		);
@@ -148,6 +439,7 @@ impl LoginScreen {
 		c: &mut TibsClayScope<'clay, 'render>,
 		user: &'render User,
 		is_selected: bool,
+		has_session: bool,
 	) where
 		'clay: 'render,
 	{
@@ -200,13 +492,41 @@ impl LoginScreen {
 					.alignment(clay_layout::text::TextAlignment::Left)
 					.end(),
 			);
+			// Logging in as this user switches back to their session
+			if has_session {
+				c.with(
+					Declaration::new()
+						.layout()
+						.padding(Padding::new(8, 8, 2, 2))
+						.end()
+						.background_color((0x4C, 0xAF, 0x50, 0x80).into())
+						.corner_radius()
+						.all(99999.0)
+						.end(),
+					|c| {
+						c.text(
+							"Logged in",
+							TextConfig::new()
+								.color((0xFF, 0xFF, 0xFF).into())
+								.font_size(12)
+								.end(),
+						);
+					},
+				);
+			}
 		});
 	}
 
-	fn render_selected_user<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>, login_manager: &LoginManager)
+	fn render_selected_user<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>, login_manager: &LoginManager, session_manager: &SessionManager)
 	where
 		'clay: 'render,
 	{
+		// lastlog already lists a running session as the last login, which isn't news
+		// to the user who started it
+		let last_login = self
+			.last_login
+			.as_ref()
+			.filter(|_| !session_manager.is_running(self.selected_user));
 		// Retrieve the selected user info
 		if let Some(selected) = self
 			.user_list
@@ -268,6 +588,16 @@ impl LoginScreen {
 									.alignment(clay_layout::text::TextAlignment::Center)
 									.end(),
 							);
+							if let Some(last_login) = last_login {
+								c.text(
+									last_login,
+									TextConfig::new()
+										.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+										.font_size(14)
+										.alignment(clay_layout::text::TextAlignment::Center)
+										.end(),
+								);
+							}
 
 							c.with(
 								Declaration::new()
@@ -284,6 +614,21 @@ impl LoginScreen {
 									self.render_login_button(c, login_manager);
 								},
 							);
+							self.render_lock_indicators(c);
+							if let Some((_, _, hint)) = self
+								.session_choice
+								.as_ref()
+								.filter(|(uid, _, _)| *uid == selected.uid())
+							{
+								c.text(
+									hint,
+									TextConfig::new()
+										.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+										.font_size(14)
+										.end(),
+								);
+							}
+							self.crash_panel.render(c, selected.uid());
 						},
 					);
 				},
@@ -350,6 +695,80 @@ impl LoginScreen {
 		});
 }
 
+	fn render_lock_indicators<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		c.with(
+			Declaration::new()
+				.layout()
+				.direction(LayoutDirection::LeftToRight)
+				.child_alignment(Alignment::new(LX::Center, LY::Center))
+				.padding(Padding::new(0, 0, 12, 0))
+				.height(fixed!(36.0))
+				.child_gap(10)
+				.end(),
+			|c| {
+				if self.caps_lock {
+					c.with(
+						Declaration::new()
+							.layout()
+							.direction(LayoutDirection::LeftToRight)
+							.child_alignment(Alignment::new(LX::Center, LY::Center))
+							.padding(Padding::new(10, 10, 4, 4))
+							.child_gap(8)
+							.end()
+							.background_color((0xFF, 0xCC, 0x00, 0x20).into())
+							.corner_radius()
+							.all(8.0)
+							.end(),
+						|c| {
+							c.with(
+								Declaration::new()
+									.image()
+									.data(&self.alert_icon)
+									.end()
+									.layout()
+									.width(fixed!(16.0))
+									.height(fixed!(16.0))
+									.end(),
+								|_| {},
+							);
+							c.text(
+								"Caps Lock is on",
+								TextConfig::new()
+									.color((0xFF, 0xCC, 0x00).into())
+									.font_size(14)
+									.end(),
+							);
+						},
+					);
+				}
+				if self.num_lock {
+					c.with(
+						Declaration::new()
+							.layout()
+							.padding(Padding::new(10, 10, 4, 4))
+							.end()
+							.background_color((0x0E, 0x1A, 0x26, 0x30).into())
+							.corner_radius()
+							.all(8.0)
+							.end(),
+						|c| {
+							c.text(
+								"Num Lock",
+								TextConfig::new()
+									.color((0xFF, 0xFF, 0xFF, 0xA0).into())
+									.font_size(14)
+									.end(),
+							);
+						},
+					);
+				}
+			},
+		);
+	}
+
 	fn render_eye_button<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
 	where
 		'clay: 'render,
@@ -0,0 +1,197 @@
+use nix::libc;
+use std::{
+	ffi::{CStr, CString},
+	fs::OpenOptions,
+	io, mem,
+	os::unix::fs::FileExt,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const WTMP_PATH: &str = "/var/log/wtmp";
+const LASTLOG_PATH: &str = "/var/log/lastlog";
+/// Size of `struct lastlog` from glibc, which keeps a 32 bit timestamp for compatibility
+const LASTLOG_RECORD_SIZE: usize = 4 + LASTLOG_LINE_SIZE + LASTLOG_HOST_SIZE;
+const LASTLOG_LINE_SIZE: usize = 32;
+const LASTLOG_HOST_SIZE: usize = 256;
+
+extern "C" {
+	fn updwtmpx(wtmpx_file: *const libc::c_char, utmpx: *const libc::utmpx);
+}
+
+/// When and where a user last logged in, from lastlog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastLogin {
+	pub time: SystemTime,
+	pub line: String,
+	pub host: String,
+}
+
+impl LastLogin {
+	/// The last login of a user, `None` if they never logged in or lastlog doesn't exist.
+	pub fn of_user(uid: u32) -> Option<Self> {
+		let file = OpenOptions::new().read(true).open(LASTLOG_PATH).ok()?;
+		let mut record = [0u8; LASTLOG_RECORD_SIZE];
+		file
+			.read_exact_at(&mut record, uid as u64 * LASTLOG_RECORD_SIZE as u64)
+			.ok()?;
+		Self::decode(&record)
+	}
+
+	/// Format like `Last login: Sat Oct 17 21:04 on tty3`.
+	pub fn describe(&self) -> String {
+		let seconds = self
+			.time
+			.duration_since(UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_secs();
+		let mut description = format!("Last login: {}", format_local_time(seconds as libc::time_t));
+		if !self.line.is_empty() {
+			description += &format!(" on {}", self.line);
+		}
+		if !self.host.is_empty() {
+			description += &format!(" from {}", self.host);
+		}
+		description
+	}
+
+	fn decode(record: &[u8; LASTLOG_RECORD_SIZE]) -> Option<Self> {
+		let time = i32::from_ne_bytes(record[..4].try_into().unwrap());
+		if time <= 0 {
+			return None;
+		}
+		let line = &record[4..4 + LASTLOG_LINE_SIZE];
+		let host = &record[4 + LASTLOG_LINE_SIZE..];
+		Some(Self {
+			time: UNIX_EPOCH + Duration::from_secs(time as u64),
+			line: c_field_to_string(line),
+			host: c_field_to_string(host),
+		})
+	}
+
+	fn encode(&self) -> [u8; LASTLOG_RECORD_SIZE] {
+		let mut record = [0u8; LASTLOG_RECORD_SIZE];
+		let time = self
+			.time
+			.duration_since(UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_secs() as i32;
+		record[..4].copy_from_slice(&time.to_ne_bytes());
+		copy_c_field(&mut record[4..4 + LASTLOG_LINE_SIZE], &self.line);
+		copy_c_field(&mut record[4 + LASTLOG_LINE_SIZE..], &self.host);
+		record
+	}
+}
+
+/// Record a session starting in utmp, wtmp and lastlog, so it shows up in `who`, `w` and `last`.
+pub fn record_login(username: &str, uid: u32, pid: u32, tty: u16) {
+	let mut entry = utmpx_entry(libc::USER_PROCESS, pid, tty);
+	copy_c_field(as_bytes_mut(&mut entry.ut_user), username);
+	write_utmp(&entry);
+	let last_login = LastLogin {
+		time: SystemTime::now(),
+		line: tty_line(tty),
+		host: String::new(),
+	};
+	if let Err(e) = write_lastlog(uid, &last_login) {
+		log::warn!("Failed to update {LASTLOG_PATH}: {e}");
+	}
+}
+
+/// Mark the session as ended in utmp and wtmp.
+pub fn record_logout(pid: u32, tty: u16) {
+	write_utmp(&utmpx_entry(libc::DEAD_PROCESS, pid, tty));
+}
+
+fn utmpx_entry(kind: libc::c_short, pid: u32, tty: u16) -> libc::utmpx {
+	let mut entry: libc::utmpx = unsafe { mem::zeroed() };
+	let now = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.unwrap_or_default();
+	entry.ut_type = kind;
+	entry.ut_pid = pid as libc::pid_t;
+	copy_c_field(as_bytes_mut(&mut entry.ut_line), &tty_line(tty));
+	// Like getty, the id is the part of the line after "tty"
+	copy_c_field(as_bytes_mut(&mut entry.ut_id), &tty.to_string());
+	entry.ut_tv.tv_sec = now.as_secs() as _;
+	entry.ut_tv.tv_usec = now.subsec_micros() as _;
+	entry
+}
+
+fn write_utmp(entry: &libc::utmpx) {
+	unsafe {
+		libc::setutxent();
+		if libc::pututxline(entry).is_null() {
+			log::warn!("Failed to write to utmp: {}", io::Error::last_os_error());
+		}
+		libc::endutxent();
+		let wtmp = CString::new(WTMP_PATH).unwrap();
+		updwtmpx(wtmp.as_ptr(), entry);
+	}
+}
+
+fn write_lastlog(uid: u32, last_login: &LastLogin) -> io::Result<()> {
+	// Like login, only update lastlog if the system keeps one
+	let file = OpenOptions::new().write(true).open(LASTLOG_PATH)?;
+	file.write_all_at(
+		&last_login.encode(),
+		uid as u64 * LASTLOG_RECORD_SIZE as u64,
+	)
+}
+
+fn tty_line(tty: u16) -> String {
+	format!("tty{tty}")
+}
+
+fn format_local_time(time: libc::time_t) -> String {
+	let mut tm: libc::tm = unsafe { mem::zeroed() };
+	let mut buffer = [0 as libc::c_char; 64];
+	let written = unsafe {
+		libc::localtime_r(&time, &mut tm);
+		libc::strftime(
+			buffer.as_mut_ptr(),
+			buffer.len(),
+			c"%a %b %e %H:%M".as_ptr(),
+			&tm,
+		)
+	};
+	if written == 0 {
+		return String::new();
+	}
+	unsafe { CStr::from_ptr(buffer.as_ptr()) }
+		.to_string_lossy()
+		.into_owned()
+}
+
+fn as_bytes_mut(field: &mut [libc::c_char]) -> &mut [u8] {
+	unsafe { std::slice::from_raw_parts_mut(field.as_mut_ptr() as *mut u8, field.len()) }
+}
+
+/// Copy a string into a fixed size C field, truncating it if it doesn't fit.
+///
+/// Like in utmp, the field is only NUL terminated if there's room left.
+fn copy_c_field(field: &mut [u8], value: &str) {
+	let len = value.len().min(field.len());
+	field[..len].copy_from_slice(&value.as_bytes()[..len]);
+	field[len..].fill(0);
+}
+
+fn c_field_to_string(field: &[u8]) -> String {
+	let len = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+	String::from_utf8_lossy(&field[..len]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_lastlog_record_roundtrip() {
+		let last_login = LastLogin {
+			time: UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+			line: "tty3".into(),
+			host: String::new(),
+		};
+		assert_eq!(LastLogin::decode(&last_login.encode()), Some(last_login));
+		assert_eq!(LastLogin::decode(&[0; LASTLOG_RECORD_SIZE]), None);
+	}
+}
@@ -7,23 +7,37 @@ use clay_layout::{
 };
 use rustamarine::screen::Screen;
 pub mod skia_clay;
+pub mod admin_auth;
 pub mod background;
 pub mod custom_elements;
 pub mod fps_counter;
 pub mod gl;
 pub mod gl_errors;
+pub mod greeter_service;
+pub mod input_config;
+pub mod kernel_log;
+pub mod keyboard_layout;
 #[macro_use]
 pub mod animation;
 pub mod cursor;
 pub mod loading_screen;
 pub mod login;
+pub mod login_records;
+pub mod on_screen_keyboard;
+pub mod pointer;
+pub mod power;
 pub mod progress_watcher;
+pub mod session_log;
 pub mod session_manager;
+pub mod session_scope;
+pub mod session_supervisor;
 pub mod skia;
 pub mod skia_image_asset;
 pub mod skia_shader_asset;
 pub mod textbox;
 pub mod tty;
+pub mod unit_status;
+pub mod vt_switch;
 pub type TibsClayScope<'clay, 'render> =
 	SkiaClayScope<'clay, 'render, custom_elements::CustomElements>;
 
@@ -32,8 +46,8 @@ use crate::{
 	cursor::Cursor,
 	custom_elements::CustomElements,
 	loading_screen::LoadingScreen,
-	login::LoginManager,
-	session_manager::SessionManager, skia_clay::{create_measure_text_function, SkiaClayScope},
+	login::{CrashReport, LoginManager, LoginState, SessionChoice},
+	session_manager::{DesktopEnvironmentFile, SessionManager, SessionStatus}, skia_clay::{create_measure_text_function, SkiaClayScope},
 };
 use assets_manager::AssetCache;
 use skia::{create_skia_surface, init_skia};
@@ -42,6 +56,7 @@ use std::{
 	rc::Rc,
 	sync::{LazyLock, Mutex},
 };
+use uzers::os::unix::UserExt;
 
 static UBUNTU_FONT: LazyLock<Typeface> = LazyLock::new(|| {
 	FontMgr::new()
@@ -64,7 +79,40 @@ impl SkiaContext {
 	}
 }
 
+/// What tibs was started for, picked with its first argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+	/// Boot splash followed by the login screen
+	Greeter,
+	/// Shutdown splash, started by the power off and reboot targets
+	PowerOff,
+	Reboot,
+}
+
+/// The parts of tibs only the greeter needs, the shutdown splash goes without them.
+struct Greeter {
+	login_screen: login_screen::LoginScreen,
+	vt_switcher: vt_switch::VtSwitcher,
+	login_manager: LoginManager,
+	session_manager: SessionManager,
+	greeter_service: greeter_service::GreeterService,
+}
+
+impl Greeter {
+	fn new(rmar: &rustamarine::Rustamarine, assets: &Rc<AssetCache>) -> color_eyre::Result<Self> {
+		Ok(Self {
+			login_screen: login_screen::LoginScreen::new(assets),
+			vt_switcher: vt_switch::VtSwitcher::from_env(),
+			login_manager: LoginManager::new(),
+			session_manager: SessionManager::new(rmar.waker()?),
+			greeter_service: greeter_service::GreeterService::new(rmar.waker()?),
+		})
+	}
+}
+
 struct AppState {
+	/// `None` in the shutdown splash
+	greeter: Option<Greeter>,
 	boot_progress: progress_watcher::ProgressWatcher,
 	fps_counter: fps_counter::FPSCounter,
 	last_time: std::time::Instant,
@@ -72,8 +120,8 @@ struct AppState {
 	context: Option<SkiaContext>,
 	assets: Rc<AssetCache>,
 	loading_screen: LoadingScreen,
-	login_screen: login_screen::LoginScreen,
 	cursor: Cursor,
+	pointer: pointer::Pointer,
 	skip_animation: bool,
 	screen_slide_animation: BasicAnimation,
 	show_login_screen: bool,
@@ -81,8 +129,8 @@ struct AppState {
 	devtools: bool,
 	background: Background,
 	should_exit: bool,
-	login_manager: LoginManager,
-	session_manager: SessionManager,
+	/// Whether we own the display, false while switched to another VT
+	session_active: bool,
 }
 
 fn update_app_state(state: &mut AppState, rmar: &rustamarine::Rustamarine, screen: &mut Screen) {
@@ -97,32 +145,64 @@ fn update_app_state(state: &mut AppState, rmar: &rustamarine::Rustamarine, scree
 	rmar.set_mouse_x(mouse_x.max(0).min(screen_width));
 	rmar.set_mouse_y(mouse_y.max(0).min(screen_height));
 
+	// Start the sessions of users that finished logging in, before the progress borrows the state
+	if let Some(greeter) = &mut state.greeter {
+		while let Some((username, uid)) = greeter.login_manager.take_authenticated() {
+			start_session(greeter, rmar, &username, uid);
+		}
+	}
+
 	let progress = state.boot_progress.poll_progress();
 
 	let current_time = std::time::Instant::now();
 
+	let dev_mode = std::env::var("TIBS_DEV_MODE") == Ok("1".to_string());
+
 	// Handle escape key to exit
-	if rmar.is_key_down(rustamarine::keys::KEY_Escape)
-		&& std::env::var("TIBS_DEV_MODE") == Ok("1".to_string())
-	{
+	if rmar.is_key_down(rustamarine::keys::KEY_Escape) && dev_mode {
 		state.should_exit = true;
 		return;
 	}
 
-	// Toggle devtools with Caps Lock
-	if rmar.is_key_pressed(rustamarine::keys::KEY_Caps_Lock)
-		&& std::env::var("TIBS_DEV_MODE") == Ok("1".to_string())
+	// Toggle devtools with a bare F12, Ctrl+Alt+F12 switches VTs
+	if rustamarine::Shortcut::new(rustamarine::Modifiers::empty(), rustamarine::keys::KEY_F12)
+		.matches_modifiers(rmar.get_modifiers())
+		&& rmar.is_key_pressed(rustamarine::keys::KEY_F12)
+		&& dev_mode
 	{
 		state.devtools = !state.devtools;
 		state.clay.set_debug_mode(state.devtools);
 	}
 
+	// Switch to a text console with Ctrl+Alt+Fn
+	if let Some(vt) = state
+		.greeter
+		.as_ref()
+		.and_then(|g| g.vt_switcher.requested_vt(rmar))
+	{
+		rmar.go_to_tty(vt);
+		return;
+	}
+
+	// Show the boot details with F2, or with Esc unless dev mode uses it to exit
+	if !state.show_login_screen
+		&& ((rmar.is_key_pressed(rustamarine::keys::KEY_Escape) && !dev_mode)
+			|| rmar.is_key_pressed(rustamarine::keys::KEY_F2))
+	{
+		state.loading_screen.toggle_details();
+	}
+
 	// Calculate delta time
 	let delta = current_time.duration_since(state.last_time).as_secs_f32();
 	state.last_time = current_time;
 
-	// Get mouse position
-	let mouse_position = (rmar.get_mouse_x() as f32, rmar.get_mouse_y() as f32);
+	// Update the pointer from the mouse or touchscreen
+	state.pointer.update(rmar);
+
+	// Update clay pointer state
+	state
+		.clay
+		.pointer_state(state.pointer.position().into(), state.pointer.down);
 
 	// Update animation
 	if !state.skip_animation {
@@ -139,18 +219,32 @@ fn update_app_state(state: &mut AppState, rmar: &rustamarine::Rustamarine, scree
 			state.background.time_offset = state.screen_slide_animation_progress * 5.0;
 		}
 	}
+	let mut authenticated = false;
+	// The failed units take the keyboard for their admin login
+	if let Some(greeter) = state
+		.greeter
+		.as_mut()
+		.filter(|_| !state.loading_screen.failed_units_open())
+	{
+		greeter.login_screen.update(
+			&mut state.clay,
+			rmar,
+			&state.pointer,
+			&mut greeter.login_manager,
+			&greeter.session_manager,
+		);
+		authenticated = matches!(
+			greeter
+				.login_manager
+				.get_current_login_state(greeter.login_screen.username()),
+			Some(LoginState::Authenticated(_))
+		);
+	}
 	state
-		.login_screen
-		.update(&mut state.clay, rmar, &mut state.login_manager);
-	state.loading_screen.update(&progress, delta);
+		.loading_screen
+		.update(&mut state.clay, rmar, &state.pointer, &progress, delta);
 	// Update background
-	state.background.update(delta, &state.login_manager, &state.login_screen);
-
-	// Update clay pointer state
-	state.clay.pointer_state(
-		(mouse_position.0, mouse_position.1).into(),
-		rmar.is_mouse_button_down(0),
-	);
+	state.background.update(delta, authenticated);
 
 	// Update scroll containers
 	state.clay.update_scroll_containers(
@@ -165,6 +259,73 @@ fn update_app_state(state: &mut AppState, rmar: &rustamarine::Rustamarine, scree
 	// Hot reload assets
 	state.assets.hot_reload();
 }
+fn start_session(greeter: &mut Greeter, rmar: &rustamarine::Rustamarine, username: &str, uid: u32) {
+	if let Some(session) = greeter
+		.session_manager
+		.get_session_of_user(uid)
+		.filter(|s| s.status() == SessionStatus::Running)
+	{
+		log::info!("{username} already has a session, switching to it");
+		if let Err(e) = session.switch_to(rmar) {
+			log::error!("Failed to switch to the session of {username}: {e}");
+		}
+		return;
+	}
+	let desktop_environments = greeter.session_manager.get_desktop_environments_list();
+	let choice =
+		greeter
+			.login_screen
+			.take_session_choice(uid)
+			.unwrap_or(if desktop_environments.is_empty() {
+				SessionChoice::Failsafe
+			} else {
+				SessionChoice::Desktop(0)
+			});
+	let session_file = match choice {
+		SessionChoice::Desktop(i) => desktop_environments.get(i).cloned(),
+		SessionChoice::Failsafe => None,
+	};
+	let session_file = match session_file {
+		Some(session_file) => session_file,
+		None => {
+			let Some(user) = uzers::get_user_by_uid(uid) else {
+				log::error!("User {username} disappeared before their session started");
+				return;
+			};
+			DesktopEnvironmentFile::failsafe(user.shell())
+		}
+	};
+	log::info!("Starting {} for {username}", session_file.name());
+	// The login screen hides the last login while the session runs, lastlog lists this one now
+	if let Err(e) =
+		greeter
+			.session_manager
+			.start_session(&greeter.login_manager, username, &session_file, rmar)
+	{
+		log::error!(
+			"Failed to start {} for {username}: {e}",
+			session_file.name()
+		);
+	}
+}
+fn handle_session_event(state: &mut AppState, event: rustamarine::SessionEvent) {
+	match event {
+		rustamarine::SessionEvent::Deactivated => {
+			log::info!("Switched away from our VT, pausing rendering");
+			state.session_active = false;
+		}
+		rustamarine::SessionEvent::Activated => {
+			log::info!("Switched back to our VT, resuming rendering");
+			state.session_active = true;
+			// Don't count the time spent on another VT as one huge frame
+			state.last_time = std::time::Instant::now();
+			if let Some(ctx) = &mut state.context {
+				// Another DRM master may have touched the GL state behind skia's back
+				ctx.skia_context.reset(None);
+			}
+		}
+	}
+}
 fn ensure_skia_context(state: &mut AppState, screen: &mut Screen) {
 	let (screen_width, screen_height) = (screen.get_width() as u32, screen.get_height() as u32);
 
@@ -233,10 +394,14 @@ fn render_app(state: &mut AppState, screen: &mut Screen) {
 			|c| {
 				let continue_anyway_button_id = c.id("loading_continue_anyway_button");
 				let continue_anyway_button_clicked =
-					c.pointer_over(continue_anyway_button_id) && rmar.is_mouse_button_released(0);
-				if continue_anyway_button_clicked
-					|| (state.loading_screen.get_animation_progress("progress") >= 0.99
-						&& !progress.has_failed_services())
+					c.pointer_over(continue_anyway_button_id) && state.pointer.released;
+				// The shutdown splash stays up until the system goes down, and the boot
+				// details until they're closed
+				if state.greeter.is_some()
+					&& (continue_anyway_button_clicked
+						|| (state.loading_screen.get_animation_progress("progress") >= 0.99
+							&& !progress.has_failed_services()
+							&& !state.loading_screen.details_open()))
 				{
 					state.show_login_screen = true;
 				}
@@ -251,22 +416,26 @@ fn render_app(state: &mut AppState, screen: &mut Screen) {
 						state.loading_screen.render(progress, c);
 					},
 				);
-				c.with(
-					Declaration::new()
-						.layout()
-						.width(grow!())
-						.height(fixed!(screen_height as f32))
-						.end(),
-					|c| {
-						state.login_screen.render(c, &state.login_manager);
-					},
-				);
+				if let Some(greeter) = &state.greeter {
+					c.with(
+						Declaration::new()
+							.layout()
+							.width(grow!())
+							.height(fixed!(screen_height as f32))
+							.end(),
+						|c| {
+							greeter
+								.login_screen
+								.render(c, &greeter.login_manager, &greeter.session_manager);
+						},
+					);
+				}
 			},
 		);
 		skia_clay::clay_skia_render(canvas, c.end(), CustomElements::render, &FONTS);
 	}
 
-	if progress.finished {
+	if progress.finished && !state.pointer.is_touch {
 		state.cursor.render(canvas, &rmar, "default");
 	}
 
@@ -281,8 +450,42 @@ fn render_app(state: &mut AppState, screen: &mut Screen) {
 fn main() -> color_eyre::Result<()> {
 	color_eyre::install()?;
 	env_logger::init();
+	let mode = match std::env::args().nth(1).as_deref() {
+		Some("switch-user") => {
+			greeter_service::request_switch_user()?;
+			return Ok(());
+		}
+		Some("shutdown") => Mode::PowerOff,
+		Some("reboot") => Mode::Reboot,
+		_ => Mode::Greeter,
+	};
+	let seat_backend = match std::env::var("TIBS_SEAT_BACKEND") {
+		Ok(seat_backend) => seat_backend.parse()?,
+		Err(_) => rustamarine::SeatBackend::Auto,
+	};
+	// Set while tibs is still single threaded, libseat reads it when rustamarine starts
+	if let Some(libseat_backend) = seat_backend.libseat_backend() {
+		std::env::set_var("LIBSEAT_BACKEND", libseat_backend);
+	}
 	let mut rmar = rustamarine::Rustamarine::new();
+	if seat_backend != rustamarine::SeatBackend::Auto && !rmar.is_drm() {
+		log::warn!("Couldn't open a seat with the {seat_backend:?} backend, DRM output is unavailable");
+	}
+	if let Some(seat) = rmar.seat_name() {
+		log::info!("Using devices from {seat}");
+	}
 	gl::load_with(|n| rmar.get_opengl_proc_address(n));
+	if std::env::var("RUSTAMARINE_KB_LAYOUT").is_err() {
+		if let Some(keymap) = keyboard_layout::system_keymap() {
+			rmar.set_keymap(&keymap);
+		}
+	}
+	input_config::apply_pointer_config(&rmar);
+	match std::env::var("TIBS_NUM_LOCK").as_deref() {
+		Ok("1") => rmar.set_num_lock(true),
+		Ok("0") => rmar.set_num_lock(false),
+		_ => {}
+	}
 
 	// Create assets
 	let assets = Rc::new(AssetCache::new(
@@ -290,26 +493,42 @@ fn main() -> color_eyre::Result<()> {
 	)?);
 
 	// Initialize boot progress and check if we can skip animation
-	let mut boot_progress = progress_watcher::ProgressWatcher::new();
+	let transition = match mode {
+		Mode::Greeter => progress_watcher::Transition::Boot,
+		Mode::PowerOff | Mode::Reboot => progress_watcher::Transition::Shutdown,
+	};
+	let mut boot_progress = progress_watcher::ProgressWatcher::new(transition);
 	let skip_animation = {
 		let progress = boot_progress.poll_progress();
 		progress.finished && !progress.has_failed_services()
 	};
+	let mut loading_screen = LoadingScreen::new(&assets);
+	loading_screen.set_transition(transition);
+	match mode {
+		Mode::Greeter => {}
+		Mode::PowerOff => loading_screen.set_caption("Shutting down"),
+		Mode::Reboot => loading_screen.set_caption("Restarting"),
+	}
 
 	// Create clay layout
 	let mut clay = clay_layout::Clay::new((0.0, 0.0).into());
 	clay.set_measure_text_function(create_measure_text_function(&FONTS));
 
 	// Create app state
+	let greeter = match mode {
+		Mode::Greeter => Some(Greeter::new(&rmar, &assets)?),
+		Mode::PowerOff | Mode::Reboot => None,
+	};
 	let app_state = Mutex::new(AppState {
+		greeter,
 		boot_progress,
 		fps_counter: fps_counter::FPSCounter::new(),
 		last_time: std::time::Instant::now(),
 		clay,
 		context: None,
-		loading_screen: LoadingScreen::new(&assets),
-		login_screen: login_screen::LoginScreen::new(&assets),
+		loading_screen,
 		cursor: Cursor::new(None),
+		pointer: pointer::Pointer::default(),
 		skip_animation,
 		screen_slide_animation: BasicAnimation::new("screen_slide", 1.5, ease_in_out_circ),
 		show_login_screen: skip_animation,
@@ -318,12 +537,61 @@ fn main() -> color_eyre::Result<()> {
 		background: Background::new(Rc::clone(&assets)),
 		assets,
 		should_exit: false,
-		login_manager: LoginManager::new(),
-		session_manager: SessionManager::new(),
+		session_active: rmar.is_session_active(),
 	});
 	let start_instant = std::time::Instant::now();
 	let mut first_render = false;
 	while !app_state.lock().unwrap().should_exit {
+		while let Some(event) = rmar.poll_session_event() {
+			handle_session_event(&mut app_state.lock().unwrap(), event);
+		}
+		// Sessions can end while we're switched away, so this runs even when paused.
+		// The session supervisor wakes up poll_events when one does
+		if let Some(greeter) = app_state.lock().unwrap().greeter.as_mut() {
+			for exit in greeter.session_manager.poll_session_exits(&rmar) {
+				greeter.login_screen.refresh_last_login();
+				if exit.reason.is_success() {
+					continue;
+				}
+				let Some(session) = greeter.session_manager.get_session_of_user(exit.uid) else {
+					continue;
+				};
+				let report = CrashReport::new(
+					exit.uid,
+					&session,
+					greeter.session_manager.get_desktop_environments_list(),
+				);
+				greeter.login_screen.show_crash_report(report);
+			}
+			while let Some(pending) = greeter.greeter_service.poll_request() {
+				match pending.request {
+					greeter_service::GreeterRequest::SwitchUser { caller_uid } => {
+						// Only whoever is in front of the screen may leave their session
+						let on_screen = greeter
+							.session_manager
+							.active_session()
+							.map(|s| s.user_id());
+						if on_screen != Some(caller_uid) {
+							log::warn!(
+								"Uid {caller_uid} asked to switch users without owning the session on screen"
+							);
+							pending.deny();
+							continue;
+						}
+						log::info!("A session asked to switch users, showing the greeter");
+						if let Err(e) = greeter.session_manager.switch_to_greeter(&rmar) {
+							log::error!("Failed to switch to the greeter: {e}");
+						}
+						pending.accept();
+					}
+				}
+			}
+		}
+		if !app_state.lock().unwrap().session_active {
+			// Nothing to draw or read until we get our VT back
+			rmar.poll_events();
+			continue;
+		}
 		let mut screens = rmar.screens();
 		let Some(mut screen) = screens.first_mut() else {
 			rmar.poll_events();
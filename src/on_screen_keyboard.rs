@@ -0,0 +1,284 @@
+use crate::{pointer::Pointer, TibsClayScope};
+use clay_layout::{
+	fixed, grow,
+	layout::{Alignment, LayoutAlignmentX as LX, LayoutAlignmentY as LY, LayoutDirection, Padding},
+	text::TextConfig,
+	Declaration,
+};
+
+/// What the user typed on the on-screen keyboard this frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OnScreenKeyboardEvent {
+	Text(String),
+	Backspace,
+	Enter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Page {
+	Lowercase,
+	Uppercase,
+	Symbols,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+	Char(char),
+	Shift,
+	Backspace,
+	SwitchPage,
+	Space,
+	Enter,
+}
+
+const LETTER_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+const SYMBOL_ROWS: [&str; 3] = ["@#$%&-+()/", "*\"':;!?~_", "=\\|<>[]{}"];
+
+/// A touch friendly keyboard, for devices that boot without a physical keyboard attached.
+pub struct OnScreenKeyboard {
+	page: Page,
+	/// Keys and their labels for every page, indexed by [`Page`]
+	pages: [Vec<Vec<(Key, String)>>; 3],
+	pub visible: bool,
+}
+
+impl OnScreenKeyboard {
+	pub fn new() -> Self {
+		let pages = [Page::Lowercase, Page::Uppercase, Page::Symbols].map(|page| {
+			Self::rows(page)
+				.into_iter()
+				.map(|keys| {
+					keys
+						.into_iter()
+						.map(|key| (key, Self::key_label(page, key)))
+						.collect()
+				})
+				.collect()
+		});
+		Self {
+			page: Page::Lowercase,
+			pages,
+			visible: false,
+		}
+	}
+
+	fn rows(page: Page) -> Vec<Vec<Key>> {
+		let chars = |row: &str| row.chars().map(Key::Char).collect::<Vec<_>>();
+		let middle_rows = match page {
+			Page::Lowercase | Page::Uppercase => LETTER_ROWS,
+			Page::Symbols => SYMBOL_ROWS,
+		};
+		let mut third_row = vec![Key::Shift];
+		third_row.extend(chars(middle_rows[2]));
+		third_row.push(Key::Backspace);
+		vec![
+			chars("1234567890"),
+			chars(middle_rows[0]),
+			chars(middle_rows[1]),
+			third_row,
+			vec![Key::SwitchPage, Key::Char(','), Key::Space, Key::Char('.'), Key::Enter],
+		]
+	}
+
+	fn key_id(row: usize, column: usize) -> String {
+		format!("osk-key-{row}-{column}")
+	}
+
+	fn key_label(page: Page, key: Key) -> String {
+		match key {
+			Key::Char(ch) if page == Page::Uppercase => ch.to_uppercase().collect(),
+			Key::Char(ch) => ch.to_string(),
+			Key::Shift => "Shift".into(),
+			Key::Backspace => "Back".into(),
+			Key::SwitchPage if page == Page::Symbols => "ABC".into(),
+			Key::SwitchPage => "?123".into(),
+			Key::Space => " ".into(),
+			Key::Enter => "Enter".into(),
+		}
+	}
+
+	fn current_page(&self) -> &Vec<Vec<(Key, String)>> {
+		&self.pages[self.page as usize]
+	}
+
+	pub fn update(
+		&mut self,
+		c: &mut clay_layout::Clay,
+		pointer: &Pointer,
+	) -> Option<OnScreenKeyboardEvent> {
+		if !self.visible || !pointer.pressed {
+			return None;
+		}
+		let key = self
+			.current_page()
+			.iter()
+			.enumerate()
+			.find_map(|(row, keys)| {
+				keys
+					.iter()
+					.enumerate()
+					.find(|(column, _)| c.pointer_over(c.id(&Self::key_id(row, *column))))
+					.map(|(_, (key, _))| *key)
+			})?;
+		self.press(key)
+	}
+
+	fn press(&mut self, key: Key) -> Option<OnScreenKeyboardEvent> {
+		match key {
+			Key::Char(_) => {
+				let text = Self::key_label(self.page, key);
+				// Shift only applies to one letter
+				if self.page == Page::Uppercase {
+					self.page = Page::Lowercase;
+				}
+				Some(OnScreenKeyboardEvent::Text(text))
+			}
+			Key::Space => Some(OnScreenKeyboardEvent::Text(" ".into())),
+			Key::Backspace => Some(OnScreenKeyboardEvent::Backspace),
+			Key::Enter => Some(OnScreenKeyboardEvent::Enter),
+			Key::Shift => {
+				self.page = match self.page {
+					Page::Lowercase => Page::Uppercase,
+					_ => Page::Lowercase,
+				};
+				None
+			}
+			Key::SwitchPage => {
+				self.page = match self.page {
+					Page::Symbols => Page::Lowercase,
+					_ => Page::Symbols,
+				};
+				None
+			}
+		}
+	}
+
+	pub fn render<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
+	where
+		'clay: 'render,
+	{
+		if !self.visible {
+			return;
+		}
+		c.with(
+			Declaration::new()
+				.layout()
+				.direction(LayoutDirection::TopToBottom)
+				.child_alignment(Alignment::new(LX::Center, LY::Center))
+				.padding(Padding::all(10))
+				.child_gap(8)
+				.end()
+				.background_color((0x0E, 0x1A, 0x26, 0xC0).into())
+				.corner_radius()
+				.all(14.0)
+				.end(),
+			|c| {
+				for (row, keys) in self.current_page().iter().enumerate() {
+					c.with(
+						Declaration::new()
+							.layout()
+							.direction(LayoutDirection::LeftToRight)
+							.child_gap(8)
+							.end(),
+						|c| {
+							for (column, (key, label)) in keys.iter().enumerate() {
+								self.render_key(c, row, column, *key, label);
+							}
+						},
+					);
+				}
+			},
+		);
+	}
+
+	fn render_key<'clay, 'render>(
+		&'render self,
+		c: &mut TibsClayScope<'clay, 'render>,
+		row: usize,
+		column: usize,
+		key: Key,
+		label: &'render str,
+	) where
+		'clay: 'render,
+	{
+		let id = c.id(&Self::key_id(row, column));
+		let width = match key {
+			Key::Char(_) => fixed!(56.0),
+			Key::Space => fixed!(300.0),
+			_ => grow!(84.0),
+		};
+		let mut decl = Declaration::new();
+		decl
+			.id(id)
+			.layout()
+			.child_alignment(Alignment::new(LX::Center, LY::Center))
+			.width(width)
+			.height(fixed!(56.0))
+			.end()
+			.corner_radius()
+			.all(10.0)
+			.end();
+		let highlighted = key == Key::Shift && self.page == Page::Uppercase;
+		if c.pointer_over(id) || highlighted {
+			decl.background_color((0x2E, 0x3E, 0x4E, 0x80).into());
+		} else {
+			decl.background_color((0x2E, 0x3E, 0x4E, 0x30).into());
+		}
+		c.with(&decl, |c| {
+			c.text(
+				label,
+				TextConfig::new()
+					.color((0xFF, 0xFF, 0xFF).into())
+					.font_size(20)
+					.alignment(clay_layout::text::TextAlignment::Center)
+					.end(),
+			);
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_key_presses() {
+		let mut osk = OnScreenKeyboard::new();
+		assert_eq!(
+			osk.press(Key::Char('a')),
+			Some(OnScreenKeyboardEvent::Text("a".into()))
+		);
+		assert_eq!(osk.press(Key::Shift), None);
+		assert_eq!(
+			osk.press(Key::Char('a')),
+			Some(OnScreenKeyboardEvent::Text("A".into()))
+		);
+		// Shift is released after one letter
+		assert_eq!(
+			osk.press(Key::Char('b')),
+			Some(OnScreenKeyboardEvent::Text("b".into()))
+		);
+		assert_eq!(
+			osk.press(Key::Space),
+			Some(OnScreenKeyboardEvent::Text(" ".into()))
+		);
+		assert_eq!(
+			osk.press(Key::Backspace),
+			Some(OnScreenKeyboardEvent::Backspace)
+		);
+		assert_eq!(osk.press(Key::Enter), Some(OnScreenKeyboardEvent::Enter));
+	}
+
+	#[test]
+	fn test_symbol_page() {
+		let mut osk = OnScreenKeyboard::new();
+		assert_eq!(osk.press(Key::SwitchPage), None);
+		assert_eq!(osk.current_page()[1][0].1, "@");
+		assert_eq!(
+			osk.press(Key::Char('@')),
+			Some(OnScreenKeyboardEvent::Text("@".into()))
+		);
+		assert_eq!(osk.press(Key::SwitchPage), None);
+		assert_eq!(osk.current_page()[1][0].1, "q");
+	}
+}
@@ -0,0 +1,66 @@
+use rustamarine::Rustamarine;
+
+/// Unified pointer state for the UI, driven by the mouse or by the first finger on a touchscreen.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Pointer {
+	pub x: f32,
+	pub y: f32,
+	/// The left mouse button is held or a finger is touching the screen
+	pub down: bool,
+	/// Became down this frame
+	pub pressed: bool,
+	/// Stopped being down this frame
+	pub released: bool,
+	/// Whether the last interaction came from a touchscreen
+	pub is_touch: bool,
+	touch_id: Option<i32>,
+}
+
+impl Pointer {
+	pub fn update(&mut self, rmar: &Rustamarine) {
+		// The UI is only drawn on the first screen, touches on other screens would land on
+		// whatever is at the same position there
+		let touches = rmar
+			.get_touch_points()
+			.into_iter()
+			.filter(|t| t.screen == 0)
+			.collect::<Vec<_>>();
+		let tracked_touch = self
+			.touch_id
+			.and_then(|id| touches.iter().find(|t| t.id == id))
+			.or_else(|| touches.iter().find(|t| t.pressed));
+		if let Some(touch) = tracked_touch {
+			self.touch_id = (!touch.released).then_some(touch.id);
+			self.x = touch.x;
+			self.y = touch.y;
+			self.pressed = touch.pressed;
+			self.released = touch.released;
+			self.down = !touch.released;
+			self.is_touch = true;
+			return;
+		}
+		self.touch_id = None;
+
+		let mouse_used = rmar.get_mouse_delta_x() != 0
+			|| rmar.get_mouse_delta_y() != 0
+			|| rmar.is_mouse_button_down(0)
+			|| rmar.is_mouse_button_released(0);
+		if self.is_touch && !mouse_used {
+			// Keep the pointer where the finger was lifted
+			self.pressed = false;
+			self.released = false;
+			self.down = false;
+			return;
+		}
+		self.is_touch = false;
+		self.x = rmar.get_mouse_x() as f32;
+		self.y = rmar.get_mouse_y() as f32;
+		self.down = rmar.is_mouse_button_down(0);
+		self.pressed = rmar.is_mouse_button_pressed(0);
+		self.released = rmar.is_mouse_button_released(0);
+	}
+
+	pub fn position(&self) -> (f32, f32) {
+		(self.x, self.y)
+	}
+}
@@ -0,0 +1,154 @@
+use zbus_systemd::login1::ManagerProxy;
+use zbus_systemd::zbus::{self, fdo::PropertiesProxy, names::InterfaceName, Connection};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerAction {
+	PowerOff,
+	Reboot,
+	Suspend,
+	Hibernate,
+}
+
+impl PowerAction {
+	pub const ALL: [PowerAction; 4] = [
+		PowerAction::PowerOff,
+		PowerAction::Reboot,
+		PowerAction::Suspend,
+		PowerAction::Hibernate,
+	];
+
+	pub fn label(&self) -> &'static str {
+		match self {
+			PowerAction::PowerOff => "Shut down",
+			PowerAction::Reboot => "Restart",
+			PowerAction::Suspend => "Suspend",
+			PowerAction::Hibernate => "Hibernate",
+		}
+	}
+
+	/// Nerd font glyph shown next to the label
+	pub fn icon(&self) -> &'static str {
+		match self {
+			PowerAction::PowerOff => "\u{f011}",
+			PowerAction::Reboot => "\u{f0e2}",
+			PowerAction::Suspend => "\u{f186}",
+			PowerAction::Hibernate => "\u{f0a0}",
+		}
+	}
+
+	/// The kind of inhibitor lock that can hold this action back
+	fn inhibitor_kind(&self) -> &'static str {
+		match self {
+			PowerAction::PowerOff | PowerAction::Reboot => "shutdown",
+			PowerAction::Suspend | PowerAction::Hibernate => "sleep",
+		}
+	}
+}
+
+/// A lock some program holds to delay or block an action, from logind's `ListInhibitors`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inhibitor {
+	pub who: String,
+	pub why: String,
+	/// `false` if the lock only delays the action
+	pub blocking: bool,
+}
+
+/// A session logind knows about, from `ListSessions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogindSession {
+	pub id: String,
+	pub user: String,
+	pub seat: String,
+	/// The VT the session runs on, 0 for sessions without one like ssh
+	pub vt: u32,
+}
+
+/// Which actions logind allows, in the order of [`PowerAction::ALL`].
+///
+/// Every function here blocks on D-Bus, call them from a worker thread.
+pub fn available_actions() -> zbus::Result<Vec<PowerAction>> {
+	smol::block_on(async {
+		let connection = Connection::system().await?;
+		let manager = ManagerProxy::new(&connection).await?;
+		let mut available = Vec::new();
+		for action in PowerAction::ALL {
+			let answer = match action {
+				PowerAction::PowerOff => manager.can_power_off().await?,
+				PowerAction::Reboot => manager.can_reboot().await?,
+				PowerAction::Suspend => manager.can_suspend().await?,
+				PowerAction::Hibernate => manager.can_hibernate().await?,
+			};
+			// "challenge" means polkit would ask for a password, and the greeter can't answer it
+			if answer == "yes" {
+				available.push(action);
+			}
+		}
+		Ok(available)
+	})
+}
+
+/// The inhibitors that would delay or block an action.
+pub fn inhibitors(action: PowerAction) -> zbus::Result<Vec<Inhibitor>> {
+	smol::block_on(async {
+		let connection = Connection::system().await?;
+		let manager = ManagerProxy::new(&connection).await?;
+		Ok(
+			manager
+				.list_inhibitors()
+				.await?
+				.into_iter()
+				.filter(|(what, ..)| what.split(':').any(|w| w == action.inhibitor_kind()))
+				.map(|(_, who, why, mode, _, _)| Inhibitor {
+					who,
+					why,
+					blocking: mode == "block",
+				})
+				.collect(),
+		)
+	})
+}
+
+pub fn logind_sessions() -> zbus::Result<Vec<LogindSession>> {
+	smol::block_on(async {
+		let connection = Connection::system().await?;
+		let manager = ManagerProxy::new(&connection).await?;
+		let mut sessions = Vec::new();
+		for (id, _, user, seat, path) in manager.list_sessions().await? {
+			let properties = PropertiesProxy::builder(&connection)
+				.destination("org.freedesktop.login1")?
+				.path(path)?
+				.build()
+				.await?;
+			// The session may be gone already, it's still worth listing
+			let vt = properties
+				.get(
+					InterfaceName::from_static_str_unchecked("org.freedesktop.login1.Session"),
+					"VTNr",
+				)
+				.await
+				.ok()
+				.and_then(|vt| u32::try_from(vt).ok())
+				.unwrap_or(0);
+			sessions.push(LogindSession { id, user, seat, vt });
+		}
+		Ok(sessions)
+	})
+}
+
+/// Ask logind to do the action, without an interactive polkit prompt nobody could answer.
+///
+/// Blocking inhibitors are only overridden if polkit lets tibs ignore them, otherwise
+/// logind refuses and the error says who holds the lock.
+pub fn perform(action: PowerAction) -> zbus::Result<()> {
+	smol::block_on(async {
+		let connection = Connection::system().await?;
+		let manager = ManagerProxy::new(&connection).await?;
+		match action {
+			PowerAction::PowerOff => manager.power_off(false).await,
+			PowerAction::Reboot => manager.reboot(false).await,
+			PowerAction::Suspend => manager.suspend(false).await,
+			PowerAction::Hibernate => manager.hibernate(false).await,
+		}
+	})
+}
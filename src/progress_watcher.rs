@@ -1,42 +1,115 @@
-use futures_util::{FutureExt as _, StreamExt};
-use rand::Rng;
+use futures_util::future::LocalBoxFuture;
 use smol::channel;
 use std::thread;
-use std::time::Duration;
-use std::{
-	collections::HashMap,
-	sync::{
-		atomic::{AtomicBool, Ordering},
-		Arc,
-	},
-};
-use zbus_systemd::systemd1::{ManagerProxy, UnitProxy};
-use zbus_systemd::zbus::{self, Connection};
+use std::time::{Duration, Instant};
+use std::{cell::Cell, collections::HashMap, fmt, path::PathBuf, str::FromStr, sync::Arc};
+
+mod fake;
+mod fifo;
+mod history;
+mod recorder;
+mod replay;
+mod systemd;
+mod unit_filter;
+pub use fake::FakeSource;
+pub use fifo::FifoSource;
+pub use history::{BootHistory, JobTiming};
+pub use recorder::ProgressRecorder;
+pub use replay::ReplaySource;
+pub use systemd::SystemdSource;
+pub use unit_filter::UnitFilter;
+
+/// Which system state change the progress is tracked for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Transition {
+	/// Starting up towards the default target, finished once systemd reports startup finished
+	Boot,
+	/// Stopping everything for a power off or reboot, which never finishes from our point of view
+	Shutdown,
+}
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ServiceState {
 	Loading,
+	/// The job ended with a result we don't know about, so it's neither done nor failed
+	Unknown,
 	Failed,
 	Loaded,
 }
+
+/// Something going wrong while following the progress, shown instead of leaving the bar stuck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressError {
+	/// Lost the connection to the source, trying again
+	Reconnecting(String),
+	/// The source stopped for good, so the progress was marked finished
+	SourceFailed(String),
+	/// The boot didn't finish in time, so it was marked finished
+	TimedOut(Duration),
+}
+
+impl fmt::Display for ProgressError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ProgressError::Reconnecting(reason) => {
+				write!(f, "Lost track of the progress ({reason}), reconnecting")
+			}
+			ProgressError::SourceFailed(reason) => write!(f, "Can't follow the progress: {reason}"),
+			ProgressError::TimedOut(timeout) => write!(
+				f,
+				"Still not finished after {}s, continuing anyway",
+				timeout.as_secs()
+			),
+		}
+	}
+}
+
+impl std::error::Error for ProgressError {}
+/// What the UI shows about a unit while its job runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitInfo {
+	pub description: String,
+	/// How long systemd lets the job run before giving up, `None` if there's no limit
+	pub timeout: Option<Duration>,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct ProgressData {
 	pub services: HashMap<String, ServiceState>,
+	pub unit_info: HashMap<String, UnitInfo>,
+	pub timings: HashMap<String, JobTiming>,
 	pub finished: bool,
+	pub error: Option<ProgressError>,
+	/// Durations from previous boots, to estimate the progress by time instead of by job count
+	pub history: Option<Arc<BootHistory>>,
+	/// The highest estimate shown so far, so the bar never goes back when new jobs show up
+	estimate_floor: Cell<f32>,
 }
 
 impl ProgressData {
 	pub fn get_percentage(&self) -> f32 {
 		if self.finished {
-			1.0
-		} else if self.services.is_empty() {
-			0.0
-		} else {
-			self
-				.services
-				.iter()
-				.filter(|(_, &s)| s > ServiceState::Loading)
-				.count() as f32
-				/ self.services.len() as f32
+			return 1.0;
+		}
+		let estimate = self
+			.history
+			.as_ref()
+			.and_then(|history| history.estimate(&self.services, &self.timings, Instant::now()));
+		match estimate {
+			Some(estimate) => {
+				let estimate = estimate.max(self.estimate_floor.get());
+				self.estimate_floor.set(estimate);
+				estimate
+			}
+			// First boot, nothing to estimate with
+			None if self.services.is_empty() => 0.0,
+			None => {
+				self
+					.services
+					.iter()
+					.filter(|(_, &s)| s > ServiceState::Loading)
+					.count() as f32
+					/ self.services.len() as f32
+			}
 		}
 	}
 	pub fn has_failed_services(&self) -> bool {
@@ -47,171 +120,367 @@ impl ProgressData {
 	}
 }
 
+/// A single change in progress, as reported by a [`ProgressSource`].
+///
+/// Written as one line per event, like `start foo.service`, `done foo.service`,
+/// `failed foo.service`, `unknown foo.service`, `info foo.service 90.000 Foo Daemon`
+/// (with `infinity` for no timeout) or `finished`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressEvent {
+	UnitStarted(String),
+	UnitDone(String),
+	UnitFailed(String),
+	UnitUnknown(String),
+	UnitInfo(String, UnitInfo),
+	Finished,
+}
+
+impl ProgressEvent {
+	pub fn apply(self, data: &mut ProgressData) {
+		match self {
+			ProgressEvent::UnitStarted(unit) => {
+				data.services.entry(unit).or_insert(ServiceState::Loading);
+			}
+			ProgressEvent::UnitDone(unit) => {
+				data.services.insert(unit, ServiceState::Loaded);
+			}
+			ProgressEvent::UnitFailed(unit) => {
+				data.services.insert(unit, ServiceState::Failed);
+			}
+			ProgressEvent::UnitUnknown(unit) => {
+				data.services.insert(unit, ServiceState::Unknown);
+			}
+			ProgressEvent::UnitInfo(unit, info) => {
+				data.unit_info.insert(unit, info);
+			}
+			ProgressEvent::Finished => data.finished = true,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseProgressEventError(String);
+
+impl fmt::Display for ParseProgressEventError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Invalid progress event: {:?}", self.0)
+	}
+}
+
+impl std::error::Error for ParseProgressEventError {}
+
+impl FromStr for ProgressEvent {
+	type Err = ParseProgressEventError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+		let (command, unit) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+		let unit = unit.trim();
+		match (command, unit) {
+			("finished", "") => Ok(ProgressEvent::Finished),
+			(_, "") => Err(ParseProgressEventError(s.to_string())),
+			("start", unit) => Ok(ProgressEvent::UnitStarted(unit.to_string())),
+			("done", unit) => Ok(ProgressEvent::UnitDone(unit.to_string())),
+			("failed", unit) => Ok(ProgressEvent::UnitFailed(unit.to_string())),
+			("unknown", unit) => Ok(ProgressEvent::UnitUnknown(unit.to_string())),
+			("info", rest) => {
+				let invalid = || ParseProgressEventError(s.to_string());
+				let mut parts = rest.splitn(3, char::is_whitespace);
+				let unit = parts.next().ok_or_else(invalid)?;
+				let timeout = match parts.next().ok_or_else(invalid)? {
+					"infinity" => None,
+					secs => Some(
+						secs
+							.parse::<f64>()
+							.ok()
+							.and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+							.ok_or_else(invalid)?,
+					),
+				};
+				let description = parts.next().unwrap_or_default().trim().to_string();
+				Ok(ProgressEvent::UnitInfo(
+					unit.to_string(),
+					UnitInfo {
+						description,
+						timeout,
+					},
+				))
+			}
+			_ => Err(ParseProgressEventError(s.to_string())),
+		}
+	}
+}
+
+impl fmt::Display for ProgressEvent {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ProgressEvent::UnitStarted(unit) => write!(f, "start {unit}"),
+			ProgressEvent::UnitDone(unit) => write!(f, "done {unit}"),
+			ProgressEvent::UnitFailed(unit) => write!(f, "failed {unit}"),
+			ProgressEvent::UnitUnknown(unit) => write!(f, "unknown {unit}"),
+			ProgressEvent::UnitInfo(unit, info) => match info.timeout {
+				Some(timeout) => write!(
+					f,
+					"info {unit} {:.3} {}",
+					timeout.as_secs_f64(),
+					info.description
+				),
+				None => write!(f, "info {unit} infinity {}", info.description),
+			},
+			ProgressEvent::Finished => write!(f, "finished"),
+		}
+	}
+}
+
+/// Where a [`ProgressSource`] sends its events, keeps track of the progress so far.
+pub struct ProgressSink {
+	tx: channel::Sender<ProgressData>,
+	/// Never receives anything, it's closed once the [`ProgressWatcher`] is dropped
+	shutdown: channel::Receiver<()>,
+	data: ProgressData,
+	recorder: Option<ProgressRecorder>,
+	/// Whether to remember the timings of this boot for the next one
+	save_history: bool,
+}
+
+impl ProgressSink {
+	/// Apply an event and send the new progress to the UI.
+	///
+	/// Returns `false` once the [`ProgressWatcher`] is gone, the source should stop then.
+	pub async fn send(&mut self, event: ProgressEvent) -> bool {
+		if let Some(recorder) = &mut self.recorder {
+			if let Err(e) = recorder.record(&event) {
+				log::warn!("Failed to record progress, not recording anymore: {e}");
+				self.recorder = None;
+			}
+		}
+		self.track_timing(&event);
+		let finished = event == ProgressEvent::Finished;
+		event.apply(&mut self.data);
+		if finished && self.save_history {
+			self.save_history = false;
+			if let Some(history) = &self.data.history {
+				let mut history = BootHistory::clone(history);
+				match history.update(&self.data.timings) {
+					Ok(()) => log::info!("Saved the durations of this boot"),
+					Err(e) => log::warn!("Failed to save the boot history: {e}"),
+				}
+			}
+		}
+		!self.is_shutting_down() && self.tx.send(self.data.clone()).await.is_ok()
+	}
+	fn track_timing(&mut self, event: &ProgressEvent) {
+		let now = Instant::now();
+		match event {
+			ProgressEvent::UnitStarted(unit) => {
+				self.data.timings.entry(unit.clone()).or_insert(JobTiming {
+					started: now,
+					finished: None,
+					caught_up: false,
+				});
+			}
+			ProgressEvent::UnitDone(unit)
+			| ProgressEvent::UnitFailed(unit)
+			| ProgressEvent::UnitUnknown(unit) => {
+				// Jobs we never saw start have nothing to measure
+				if let Some(timing) = self.data.timings.get_mut(unit) {
+					timing.finished.get_or_insert(now);
+				}
+			}
+			ProgressEvent::UnitInfo(..) | ProgressEvent::Finished => {}
+		}
+	}
+	/// Show an error in the UI, or clear it with `None`.
+	///
+	/// Returns `false` once the [`ProgressWatcher`] is gone, like [`ProgressSink::send`].
+	pub async fn report(&mut self, error: Option<ProgressError>) -> bool {
+		if self.data.error != error {
+			self.data.error = error;
+			if self.tx.send(self.data.clone()).await.is_err() {
+				return false;
+			}
+		}
+		!self.is_shutting_down()
+	}
+	/// The source gave up, finish so the UI doesn't wait forever.
+	fn fail(&mut self, reason: String) {
+		self.data.error = Some(ProgressError::SourceFailed(reason));
+		self.data.finished = true;
+		let _ = self.tx.try_send(self.data.clone());
+	}
+	/// Mark a job that was already running when the source found it, its duration isn't remembered.
+	pub fn mark_caught_up(&mut self, unit: &str) {
+		if let Some(timing) = self.data.timings.get_mut(unit) {
+			timing.caught_up = true;
+		}
+	}
+	pub fn progress(&self) -> &ProgressData {
+		&self.data
+	}
+	pub fn is_shutting_down(&self) -> bool {
+		self.shutdown.is_closed()
+	}
+	/// Resolves once the [`ProgressWatcher`] is dropped, to race against sources that can idle for long.
+	pub async fn wait_for_shutdown(&self) {
+		let _ = self.shutdown.recv().await;
+	}
+}
+
+/// Something that knows how far along the boot (or shutdown) is.
+///
+/// Runs on the [`ProgressWatcher`]'s thread, so it can block on its own executor.
+pub trait ProgressSource: Send + 'static {
+	/// Send events to `sink` until the progress is finished or the sink is closed.
+	fn run(self: Box<Self>, sink: &mut ProgressSink) -> LocalBoxFuture<'_, color_eyre::Result<()>>;
+	/// Whether the events come from a real boot, so their timings can be remembered for the next one.
+	fn is_live(&self) -> bool {
+		true
+	}
+}
+
+/// Pick the progress source from `TIBS_PROGRESS_SOURCE`.
+///
+/// It can be `systemd` (the default), `fake`, `replay:<timeline file>`, or `fifo:<path>`
+/// for init systems that write their progress to a FIFO.
+/// The old `TIBS_DEBUG_FAKE_PROGRESS_BAR=1` still picks `fake` when that's unset.
+fn source_from_env(transition: Transition) -> Box<dyn ProgressSource> {
+	if matches!(std::env::var("TIBS_DEBUG_FAKE_PROGRESS_BAR"), Ok(s) if s == "1") {
+		log::warn!("TIBS_DEBUG_FAKE_PROGRESS_BAR is deprecated, use TIBS_PROGRESS_SOURCE=fake");
+		if std::env::var_os("TIBS_PROGRESS_SOURCE").is_none() {
+			return Box::new(FakeSource);
+		}
+	}
+	let source = std::env::var("TIBS_PROGRESS_SOURCE").unwrap_or_default();
+	if let Some(path) = source.strip_prefix("replay:") {
+		let speed = std::env::var("TIBS_REPLAY_SPEED")
+			.ok()
+			.and_then(|s| s.trim().parse::<f64>().ok())
+			.filter(|s| *s > 0.0)
+			.unwrap_or(1.0);
+		return Box::new(ReplaySource::new(PathBuf::from(path)).with_speed(speed));
+	}
+	if let Some(path) = source.strip_prefix("fifo:") {
+		return Box::new(FifoSource::new(PathBuf::from(path)));
+	}
+	match source.as_str() {
+		"fake" => Box::new(FakeSource),
+		"" | "systemd" => Box::new(SystemdSource::new(transition)),
+		other => {
+			log::warn!("Unknown progress source {other:?}, using systemd");
+			Box::new(SystemdSource::new(transition))
+		}
+	}
+}
+
+/// Record the progress to the timeline file in `TIBS_RECORD_PROGRESS`, if it's set.
+fn recorder_from_env() -> Option<ProgressRecorder> {
+	let path = PathBuf::from(std::env::var_os("TIBS_RECORD_PROGRESS")?);
+	ProgressRecorder::create(&path)
+		.inspect_err(|e| log::warn!("Failed to create {}: {e}", path.display()))
+		.ok()
+}
+
 pub struct ProgressWatcher {
 	progress_rx: channel::Receiver<ProgressData>,
 	progress_data: ProgressData,
-	shutdown: Arc<AtomicBool>,
+	/// Dropped to tell the source to stop
+	shutdown: Option<channel::Sender<()>>,
 	handle: Option<thread::JoinHandle<()>>,
 }
 
 impl ProgressWatcher {
-	pub fn new() -> Self {
+	pub fn new(transition: Transition) -> Self {
+		// Shutdowns are too short and different every time to be worth estimating
+		let history =
+			(transition == Transition::Boot).then(|| BootHistory::load(BootHistory::path_from_env()));
+		Self::with_source(source_from_env(transition), recorder_from_env(), history)
+	}
+	pub fn with_source(
+		source: Box<dyn ProgressSource>,
+		recorder: Option<ProgressRecorder>,
+		history: Option<BootHistory>,
+	) -> Self {
 		let (tx, rx) = channel::unbounded::<ProgressData>();
-		let shutdown = Arc::new(AtomicBool::new(false));
-		let shutdown_clone = Arc::clone(&shutdown);
+		let (shutdown_tx, shutdown_rx) = channel::bounded(1);
+		let save_history = history.is_some() && source.is_live();
+		let mut sink = ProgressSink {
+			tx,
+			shutdown: shutdown_rx,
+			data: ProgressData {
+				history: history.map(Arc::new),
+				..ProgressData::default()
+			},
+			recorder,
+			save_history,
+		};
 
 		let handle = thread::spawn(move || {
-			smol::block_on(async {
-				let mut progress_data = ProgressData::default();
-				if matches!(std::env::var("TIBS_DEBUG_FAKE_PROGRESS_BAR"), Ok(s) if s == "1") {
-					fake_progress_bar(&tx, &shutdown_clone, &mut progress_data).await;
-					return Ok(());
-				}
-				let connection = Connection::system().await?;
-				let manager = ManagerProxy::new(&connection).await?;
-				// Subscribe to job new and job removed signals.
-				let mut job_new_stream = manager.receive_job_new().await?;
-				let mut job_removed_stream = manager.receive_job_removed().await?;
-				let mut system_started_up = manager.receive_startup_finished().await?;
-				let default_target_path = manager
-					.get_unit(manager.get_default_target().await?)
-					.await?;
-				let default_target = UnitProxy::new(&connection, default_target_path).await?;
-				if default_target.active_state().await? == "active" {
-					tx.send(ProgressData {
-						finished: true,
-						..Default::default()
-					})
-					.await
-					.unwrap();
-					return Ok(());
-				}
-				let jobs = manager.list_jobs().await?;
-				progress_data.services = jobs
-					.iter()
-					.filter(|job| job.3 != "done")
-					.map(|job| (job.2.clone(), ServiceState::Loading))
-					.collect();
-				let _ = tx.send(progress_data.clone()).await;
-
-				loop {
-					if shutdown_clone.load(Ordering::Relaxed) {
-						break;
-					}
-					futures_util::select! {
-							new_event = job_new_stream.next().fuse() => {
-									if let Some(new_event) = new_event {
-											let Ok(args) = new_event.args() else {
-													eprintln!("Failed to get JobNew event args");
-													continue;
-											};
-											if !progress_data.services.contains_key(&args.unit) {
-													progress_data.services.insert(args.unit, ServiceState::Loading);
-											}
-											if tx.send(progress_data.clone()).await.is_err() {
-													break;
-											};
-									}
-							},
-							removed_event = job_removed_stream.next().fuse() => {
-									if let Some(removed_event) = removed_event {
-											let Ok(args) = removed_event.args() else {
-													eprintln!("Failed to get JobRemoved event args");
-													continue;
-											};
-
-											progress_data.services.insert(args.unit, match args.result.as_str() {
-													"done" | "dependency" | "skipped" => ServiceState::Loaded,
-													"canceled" | "timeout" | "failed" => ServiceState::Failed,
-													_ => panic!("WOT??")
-											});
-											if tx.send(progress_data.clone()).await.is_err() {
-													break;
-											}
-									}
-							},
-							system_started_up_event = system_started_up.next().fuse() => {
-									if system_started_up_event.is_some() {
-											progress_data.finished = true;
-											if tx.send(progress_data.clone()).await.is_err() {
-													break;
-											}
-									}
-							}
-					}
-				}
-				zbus::Result::Ok(())
-			})
-			.unwrap();
+			if let Err(e) = smol::block_on(source.run(&mut sink)) {
+				log::error!("Failed to watch the boot progress: {e}");
+				sink.fail(e.to_string());
+			}
 		});
 
 		ProgressWatcher {
 			progress_rx: rx,
 			progress_data: ProgressData::default(),
-			shutdown,
+			shutdown: Some(shutdown_tx),
 			handle: Some(handle),
 		}
 	}
 	pub fn poll_progress(&mut self) -> &ProgressData {
-		if let Some(new_progress) = self.progress_rx.try_recv().ok() {
+		// Sources can send many events at once, only the latest progress matters
+		while let Ok(new_progress) = self.progress_rx.try_recv() {
+			new_progress
+				.estimate_floor
+				.set(self.progress_data.estimate_floor.get());
 			self.progress_data = new_progress;
 		}
 		&self.progress_data
 	}
 }
 
-async fn fake_progress_bar(
-	tx: &channel::Sender<ProgressData>,
-	shutdown_clone: &Arc<AtomicBool>,
-	progress_data: &mut ProgressData,
-) {
-	let service_names = (0..20)
-		.map(|i| format!("fake{i}.service"))
-		.collect::<Vec<String>>();
-
-	if progress_data.services.is_empty() {
-		progress_data.services = service_names
-			.into_iter()
-			.map(|s| (s.to_string(), ServiceState::Loading))
-			.collect();
-	}
-	let simulate_failure = std::env::var("TIBS_SIMULATE_BOOT_FAILURE").is_ok_and(|s| s == "1");
-	let mut rng = rand::rng();
-
-	while !shutdown_clone.load(Ordering::Relaxed) && !progress_data.finished {
-		for (_, state) in progress_data.services.iter_mut() {
-			if *state == ServiceState::Loading {
-				let chance: u8 = rng.random_range(0..100);
-				if simulate_failure {
-					if chance < 10 {
-						*state = ServiceState::Failed;
-					} else if chance < 50 {
-						*state = ServiceState::Loaded;
-					}
-				} else if chance < 50 {
-					*state = ServiceState::Loaded;
-				}
-			}
-		}
-		if progress_data
-			.services
-			.values()
-			.all(|s| *s != ServiceState::Loading)
-		{
-			progress_data.finished = true;
-		}
-		if tx.send(progress_data.clone()).await.is_err() {
-			break;
-		}
-		smol::Timer::after(Duration::from_secs(1)).await;
-	}
-}
-
 impl Drop for ProgressWatcher {
 	fn drop(&mut self) {
-		self.shutdown.store(true, Ordering::SeqCst);
+		self.shutdown.take();
 		if let Some(handle) = self.handle.take() {
 			let _ = handle.join();
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_progress_event() {
+		assert_eq!(
+			"start foo.service".parse(),
+			Ok(ProgressEvent::UnitStarted("foo.service".into()))
+		);
+		assert_eq!(
+			"failed  bar.mount\n".parse(),
+			Ok(ProgressEvent::UnitFailed("bar.mount".into()))
+		);
+		assert_eq!("finished".parse(), Ok(ProgressEvent::Finished));
+		assert_eq!(
+			"unknown foo.service".parse(),
+			Ok(ProgressEvent::UnitUnknown("foo.service".into()))
+		);
+		assert!("done".parse::<ProgressEvent>().is_err());
+		assert!("restart foo.service".parse::<ProgressEvent>().is_err());
+		let event = ProgressEvent::UnitDone("foo.service".into());
+		assert_eq!(event.to_string().parse(), Ok(event));
+		let event = ProgressEvent::UnitInfo(
+			"foo.service".into(),
+			UnitInfo {
+				description: "Foo Daemon".into(),
+				timeout: Some(Duration::from_secs(90)),
+			},
+		);
+		assert_eq!(event.to_string().parse(), Ok(event));
+		assert!("info foo.service soon Foo"
+			.parse::<ProgressEvent>()
+			.is_err());
+	}
+}
@@ -0,0 +1,59 @@
+use super::{ProgressEvent, ProgressSink, ProgressSource, ServiceState};
+use futures_util::future::LocalBoxFuture;
+use futures_util::FutureExt as _;
+use rand::Rng;
+use std::time::Duration;
+
+/// Random progress for working on the UI, fails some services if `TIBS_SIMULATE_BOOT_FAILURE=1`.
+pub struct FakeSource;
+
+impl ProgressSource for FakeSource {
+	fn run(self: Box<Self>, sink: &mut ProgressSink) -> LocalBoxFuture<'_, color_eyre::Result<()>> {
+		async move {
+			let service_names = (0..20)
+				.map(|i| format!("fake{i}.service"))
+				.collect::<Vec<String>>();
+			for name in &service_names {
+				if !sink.send(ProgressEvent::UnitStarted(name.clone())).await {
+					return Ok(());
+				}
+			}
+			let simulate_failure = std::env::var("TIBS_SIMULATE_BOOT_FAILURE").is_ok_and(|s| s == "1");
+			let mut rng = rand::rng();
+
+			while !sink.progress().finished {
+				for name in &service_names {
+					if sink.progress().services.get(name) != Some(&ServiceState::Loading) {
+						continue;
+					}
+					let chance: u8 = rng.random_range(0..100);
+					let event = if simulate_failure && chance < 10 {
+						ProgressEvent::UnitFailed(name.clone())
+					} else if chance < 50 {
+						ProgressEvent::UnitDone(name.clone())
+					} else {
+						continue;
+					};
+					if !sink.send(event).await {
+						return Ok(());
+					}
+				}
+				if sink
+					.progress()
+					.services
+					.values()
+					.all(|s| *s != ServiceState::Loading)
+					&& !sink.send(ProgressEvent::Finished).await
+				{
+					return Ok(());
+				}
+				smol::Timer::after(Duration::from_secs(1)).await;
+			}
+			Ok(())
+		}
+		.boxed_local()
+	}
+	fn is_live(&self) -> bool {
+		false
+	}
+}
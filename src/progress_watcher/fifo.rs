@@ -0,0 +1,88 @@
+use super::{ProgressEvent, ProgressSink, ProgressSource};
+use color_eyre::eyre::{bail, WrapErr};
+use futures_util::future::LocalBoxFuture;
+use futures_util::{FutureExt as _, StreamExt as _};
+use nix::libc;
+use smol::io::{AsyncBufReadExt as _, BufReader};
+use smol::Async;
+use std::{
+	ffi::CString,
+	fs::OpenOptions,
+	io,
+	os::unix::{ffi::OsStrExt, fs::FileTypeExt, fs::OpenOptionsExt},
+	path::PathBuf,
+};
+
+/// Reads events from a FIFO, one per line, so init systems other than systemd can report progress.
+///
+/// Something like `echo "done sshd" > /run/tibs/progress` from an OpenRC, runit or s6 hook is enough.
+/// Unknown lines are logged and skipped.
+pub struct FifoSource {
+	path: PathBuf,
+}
+
+impl FifoSource {
+	pub fn new(path: PathBuf) -> Self {
+		Self { path }
+	}
+
+	fn open(&self) -> color_eyre::Result<Async<std::fs::File>> {
+		match std::fs::metadata(&self.path) {
+			Ok(metadata) if !metadata.file_type().is_fifo() => {
+				bail!("{} exists and is not a FIFO", self.path.display())
+			}
+			Ok(_) => {}
+			Err(e) if e.kind() == io::ErrorKind::NotFound => {
+				if let Some(parent) = self.path.parent() {
+					std::fs::create_dir_all(parent)?;
+				}
+				let path = CString::new(self.path.as_os_str().as_bytes())?;
+				if unsafe { libc::mkfifo(path.as_ptr(), 0o600) } < 0 {
+					return Err(io::Error::last_os_error())
+						.wrap_err_with(|| format!("Failed to create {}", self.path.display()));
+				}
+			}
+			Err(e) => return Err(e.into()),
+		}
+		// Opening for writing too keeps the FIFO from hitting EOF between writers
+		let file = OpenOptions::new()
+			.read(true)
+			.write(true)
+			.custom_flags(libc::O_NONBLOCK)
+			.open(&self.path)?;
+		Ok(Async::new(file)?)
+	}
+}
+
+impl ProgressSource for FifoSource {
+	fn run(self: Box<Self>, sink: &mut ProgressSink) -> LocalBoxFuture<'_, color_eyre::Result<()>> {
+		async move {
+			let fifo = self.open()?;
+			let mut lines = BufReader::new(fifo).lines();
+			while !sink.progress().finished {
+				let line = smol::future::or(async { Some(lines.next().await) }, async {
+					sink.wait_for_shutdown().await;
+					None
+				})
+				.await;
+				let Some(Some(line)) = line else {
+					break;
+				};
+				let line = line?;
+				if line.trim().is_empty() {
+					continue;
+				}
+				match line.parse::<ProgressEvent>() {
+					Ok(event) => {
+						if !sink.send(event).await {
+							break;
+						}
+					}
+					Err(e) => log::warn!("{}: {e}", self.path.display()),
+				}
+			}
+			Ok(())
+		}
+		.boxed_local()
+	}
+}
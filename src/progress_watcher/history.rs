@@ -0,0 +1,228 @@
+use super::ServiceState;
+use std::{
+	collections::HashMap,
+	fs,
+	io::{self, Write},
+	path::PathBuf,
+	time::{Duration, Instant},
+};
+
+const DEFAULT_PATH: &str = "/var/lib/tibs/boot-history";
+/// Jobs that finish instantly, like targets, still count for a bit so they aren't ignored
+const MIN_WEIGHT: Duration = Duration::from_millis(10);
+/// How much a new boot moves the remembered duration of a job
+const NEW_BOOT_WEIGHT: f64 = 0.5;
+/// Running jobs never count as more than this fraction of done, since they can take longer than usual
+const MAX_RUNNING_CREDIT: f32 = 0.9;
+
+/// How long each job took in previous boots, stored in `/var/lib/tibs/boot-history`
+/// (or `TIBS_BOOT_HISTORY`) as `<unit> <seconds>` lines.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BootHistory {
+	path: PathBuf,
+	durations: HashMap<String, Duration>,
+}
+
+impl BootHistory {
+	pub fn path_from_env() -> PathBuf {
+		std::env::var_os("TIBS_BOOT_HISTORY")
+			.map(PathBuf::from)
+			.unwrap_or_else(|| PathBuf::from(DEFAULT_PATH))
+	}
+
+	/// Load the history, an empty one if this is the first boot.
+	pub fn load(path: PathBuf) -> Self {
+		let durations = match fs::read_to_string(&path) {
+			Ok(text) => parse_durations(&text),
+			Err(e) => {
+				if e.kind() != io::ErrorKind::NotFound {
+					log::warn!("Failed to read {}: {e}", path.display());
+				}
+				HashMap::new()
+			}
+		};
+		Self { path, durations }
+	}
+
+	/// Blend the durations of this boot into the history and save it.
+	///
+	/// Units that didn't run this boot are forgotten, so they don't hold back the estimate forever.
+	pub fn update(&mut self, timings: &HashMap<String, JobTiming>) -> io::Result<()> {
+		self.durations.retain(|unit, _| timings.contains_key(unit));
+		for (unit, timing) in timings {
+			if timing.caught_up {
+				continue;
+			}
+			let Some(duration) = timing.duration() else {
+				continue;
+			};
+			let blended = match self.durations.get(unit) {
+				Some(old) => old.mul_f64(1.0 - NEW_BOOT_WEIGHT) + duration.mul_f64(NEW_BOOT_WEIGHT),
+				None => duration,
+			};
+			self.durations.insert(unit.clone(), blended);
+		}
+		self.save()
+	}
+
+	fn save(&self) -> io::Result<()> {
+		if let Some(parent) = self.path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		let mut units = self.durations.iter().collect::<Vec<_>>();
+		units.sort();
+		// Write the whole file and then move it into place, so a crash can't leave half a history
+		let temporary_path = self.path.with_extension("tmp");
+		let mut file = fs::File::create(&temporary_path)?;
+		for (unit, duration) in units {
+			writeln!(file, "{unit} {:.3}", duration.as_secs_f64())?;
+		}
+		file.sync_all()?;
+		fs::rename(&temporary_path, &self.path)
+	}
+
+	/// Estimate how far along the boot is, from 0 to 1, weighting every job by how long it took before.
+	///
+	/// Jobs that weren't seen in previous boots count as an average job, and running jobs
+	/// get credit for the time they've been running.
+	pub fn estimate(
+		&self,
+		services: &HashMap<String, ServiceState>,
+		timings: &HashMap<String, JobTiming>,
+		now: Instant,
+	) -> Option<f32> {
+		if self.durations.is_empty() {
+			return None;
+		}
+		let average = self.durations.values().sum::<Duration>() / self.durations.len() as u32;
+		let weight = |unit: &str| {
+			self
+				.durations
+				.get(unit)
+				.copied()
+				.unwrap_or(average)
+				.max(MIN_WEIGHT)
+				.as_secs_f32()
+		};
+		let total = self
+			.durations
+			.keys()
+			.filter(|unit| !services.contains_key(*unit))
+			.chain(services.keys())
+			.map(|unit| weight(unit))
+			.sum::<f32>();
+		let done = services
+			.iter()
+			.map(|(unit, state)| match state {
+				ServiceState::Loading => {
+					let running_for = timings
+						.get(unit)
+						.map(|t| now.saturating_duration_since(t.started).as_secs_f32())
+						.unwrap_or(0.0);
+					running_for.min(weight(unit) * MAX_RUNNING_CREDIT)
+				}
+				ServiceState::Loaded | ServiceState::Failed | ServiceState::Unknown => weight(unit),
+			})
+			.sum::<f32>();
+		Some((done / total).clamp(0.0, 1.0))
+	}
+}
+
+/// When a job started and finished during this boot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobTiming {
+	pub started: Instant,
+	pub finished: Option<Instant>,
+	/// The job was already running when we found it, so `started` is only when we noticed
+	pub caught_up: bool,
+}
+
+impl JobTiming {
+	pub fn duration(&self) -> Option<Duration> {
+		Some(self.finished?.saturating_duration_since(self.started))
+	}
+}
+
+fn parse_durations(text: &str) -> HashMap<String, Duration> {
+	text
+		.lines()
+		.filter_map(|line| {
+			let (unit, seconds) = line.trim().rsplit_once(char::is_whitespace)?;
+			// Negative, infinite and huge durations can only come from a corrupted file
+			let duration = Duration::try_from_secs_f64(seconds.parse().ok()?).ok()?;
+			Some((unit.trim().to_string(), duration))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_estimate_weights_jobs_by_duration() {
+		let history = BootHistory {
+			path: PathBuf::new(),
+			durations: parse_durations("fast.service 1.0\nslow.service 9.0\n"),
+		};
+		let now = Instant::now();
+		let mut services = HashMap::from([
+			("fast.service".to_string(), ServiceState::Loaded),
+			("slow.service".to_string(), ServiceState::Loading),
+		]);
+		let timings = HashMap::from([(
+			"slow.service".to_string(),
+			JobTiming {
+				started: now,
+				finished: None,
+				caught_up: false,
+			},
+		)]);
+		assert_eq!(history.estimate(&services, &timings, now), Some(0.1));
+		services.insert("slow.service".to_string(), ServiceState::Loaded);
+		assert_eq!(history.estimate(&services, &timings, now), Some(1.0));
+		assert_eq!(
+			BootHistory::default().estimate(&services, &timings, now),
+			None
+		);
+	}
+
+	#[test]
+	fn test_update_skips_caught_up_jobs_and_forgets_old_units() {
+		let path = std::env::temp_dir().join(format!("tibs-boot-history-{}", std::process::id()));
+		let mut history = BootHistory {
+			path: path.clone(),
+			durations: parse_durations("gone.service 1.0\nearly.service 4.0\nok.service 2.0\n"),
+		};
+		let started = Instant::now();
+		let timing = |seconds, caught_up| JobTiming {
+			started,
+			finished: Some(started + Duration::from_secs(seconds)),
+			caught_up,
+		};
+		let timings = HashMap::from([
+			("early.service".to_string(), timing(1, true)),
+			("ok.service".to_string(), timing(4, false)),
+		]);
+		history.update(&timings).unwrap();
+		let _ = fs::remove_file(&path);
+		assert_eq!(
+			history.durations,
+			HashMap::from([
+				("early.service".to_string(), Duration::from_secs(4)),
+				("ok.service".to_string(), Duration::from_secs(3)),
+			])
+		);
+	}
+
+	#[test]
+	fn test_parse_durations_skips_invalid_lines() {
+		let durations = parse_durations(
+			"ok.service 1.5\ninf.service inf\nhuge.service 1e400\nnegative.service -1\nbroken\n",
+		);
+		assert_eq!(
+			durations,
+			HashMap::from([("ok.service".to_string(), Duration::from_millis(1500))])
+		);
+	}
+}
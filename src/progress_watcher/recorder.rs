@@ -0,0 +1,37 @@
+use super::ProgressEvent;
+use std::{
+	fs::File,
+	io::{self, Write},
+	path::Path,
+	time::Instant,
+};
+
+/// Writes every progress event to a timeline file that [`super::ReplaySource`] can play back.
+pub struct ProgressRecorder {
+	file: File,
+	start: Instant,
+}
+
+impl ProgressRecorder {
+	pub fn create(path: &Path) -> io::Result<Self> {
+		let mut file = File::create(path)?;
+		writeln!(
+			file,
+			"# tibs progress timeline, replay it with TIBS_PROGRESS_SOURCE=replay:{}",
+			path.display()
+		)?;
+		Ok(Self {
+			file,
+			start: Instant::now(),
+		})
+	}
+
+	/// Every event is written right away, so the timeline survives tibs crashing.
+	pub fn record(&mut self, event: &ProgressEvent) -> io::Result<()> {
+		writeln!(
+			self.file,
+			"{:.3} {event}",
+			self.start.elapsed().as_secs_f64()
+		)
+	}
+}
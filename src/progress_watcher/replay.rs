@@ -0,0 +1,108 @@
+use super::{ProgressEvent, ProgressSink, ProgressSource};
+use color_eyre::eyre::{eyre, WrapErr};
+use futures_util::future::LocalBoxFuture;
+use futures_util::FutureExt;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Plays back a recorded timeline, for reproducible demos and tests.
+///
+/// Every line of the timeline is the number of seconds since the start followed by an event,
+/// like `1.25 done foo.service`. Empty lines and lines starting with `#` are ignored.
+/// [`super::ProgressRecorder`] writes timelines in this format.
+pub struct ReplaySource {
+	path: PathBuf,
+	speed: f64,
+}
+
+impl ReplaySource {
+	pub fn new(path: PathBuf) -> Self {
+		Self { path, speed: 1.0 }
+	}
+
+	/// Play the timeline faster (or slower, below 1) than it was recorded.
+	pub fn with_speed(mut self, speed: f64) -> Self {
+		self.speed = speed;
+		self
+	}
+}
+
+impl ProgressSource for ReplaySource {
+	fn run(self: Box<Self>, sink: &mut ProgressSink) -> LocalBoxFuture<'_, color_eyre::Result<()>> {
+		async move {
+			let text = std::fs::read_to_string(&self.path)
+				.wrap_err_with(|| format!("Failed to read {}", self.path.display()))?;
+			let timeline = parse_timeline(&text)
+				.wrap_err_with(|| format!("Invalid timeline {}", self.path.display()))?;
+			let start = Instant::now();
+			for (time, event) in timeline {
+				// Events too far in the future for an Instant are never reached
+				let timer = Duration::try_from_secs_f64(time.as_secs_f64() / self.speed)
+					.ok()
+					.and_then(|delay| start.checked_add(delay))
+					.map_or_else(smol::Timer::never, smol::Timer::at);
+				futures_util::select! {
+						_ = FutureExt::fuse(timer) => {},
+						_ = sink.wait_for_shutdown().fuse() => return Ok(()),
+				}
+				if !sink.send(event).await {
+					break;
+				}
+			}
+			Ok(())
+		}
+		.boxed_local()
+	}
+	fn is_live(&self) -> bool {
+		false
+	}
+}
+
+pub fn parse_timeline(text: &str) -> color_eyre::Result<Vec<(Duration, ProgressEvent)>> {
+	text
+		.lines()
+		.enumerate()
+		.filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+		.map(|(i, line)| {
+			let (time, event) = line
+				.trim()
+				.split_once(char::is_whitespace)
+				.ok_or_else(|| eyre!("Line {}: expected a time and an event", i + 1))?;
+			let time = time
+				.parse::<f64>()
+				.ok()
+				.and_then(|t| Duration::try_from_secs_f64(t).ok())
+				.ok_or_else(|| eyre!("Line {}: invalid time {time:?}", i + 1))?;
+			let event = event.parse().wrap_err_with(|| format!("Line {}", i + 1))?;
+			Ok((time, event))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_timeline() {
+		let timeline =
+			parse_timeline("# boot\n0 start foo.service\n\n1.5 done foo.service\n2 finished\n").unwrap();
+		assert_eq!(
+			timeline,
+			vec![
+				(
+					Duration::ZERO,
+					ProgressEvent::UnitStarted("foo.service".into())
+				),
+				(
+					Duration::from_millis(1500),
+					ProgressEvent::UnitDone("foo.service".into())
+				),
+				(Duration::from_secs(2), ProgressEvent::Finished),
+			]
+		);
+		assert!(parse_timeline("soon start foo.service").is_err());
+		assert!(parse_timeline("inf finished").is_err());
+		assert!(parse_timeline("-1 finished").is_err());
+	}
+}
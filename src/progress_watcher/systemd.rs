@@ -0,0 +1,366 @@
+use super::{
+	ProgressError, ProgressEvent, ProgressSink, ProgressSource, ServiceState, Transition, UnitFilter,
+	UnitInfo,
+};
+use color_eyre::eyre::eyre;
+use futures_util::future::LocalBoxFuture;
+use futures_util::{FutureExt, StreamExt};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use zbus_systemd::systemd1::{ManagerProxy, ServiceProxy, UnitProxy};
+use zbus_systemd::zbus::{self, Connection};
+
+const DEFAULT_BOOT_TIMEOUT: Duration = Duration::from_secs(300);
+const MIN_RETRY_DELAY: Duration = Duration::from_millis(250);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Follows the jobs of systemd over D-Bus, reconnecting when the bus or systemd restarts.
+pub struct SystemdSource {
+	transition: Transition,
+	/// From `TIBS_BOOT_TIMEOUT` in seconds, 0 waits forever
+	boot_timeout: Option<Duration>,
+}
+
+/// Why following systemd stopped without an error.
+enum Interruption {
+	/// The [`super::ProgressWatcher`] is gone
+	Stopped,
+	/// systemd reloaded or re-executed, we may have missed some jobs
+	Resync,
+}
+
+/// What survives reconnecting to systemd.
+struct FollowState {
+	filter: UnitFilter,
+	/// Whether the filter already knows the dependencies of the default target
+	filter_restricted: bool,
+	deadline: Option<Instant>,
+	retry_delay: Duration,
+}
+
+impl SystemdSource {
+	pub fn new(transition: Transition) -> Self {
+		let boot_timeout = match std::env::var("TIBS_BOOT_TIMEOUT") {
+			Ok(secs) => match secs.trim().parse::<u64>() {
+				Ok(0) => None,
+				Ok(secs) => Some(Duration::from_secs(secs)),
+				Err(e) => {
+					log::warn!("Invalid TIBS_BOOT_TIMEOUT {secs:?}: {e}");
+					Some(DEFAULT_BOOT_TIMEOUT)
+				}
+			},
+			Err(_) => Some(DEFAULT_BOOT_TIMEOUT),
+		};
+		Self {
+			transition,
+			// A shutdown ends with the machine going down anyway
+			boot_timeout: boot_timeout.filter(|_| transition == Transition::Boot),
+		}
+	}
+}
+
+impl ProgressSource for SystemdSource {
+	fn run(self: Box<Self>, sink: &mut ProgressSink) -> LocalBoxFuture<'_, color_eyre::Result<()>> {
+		async move {
+			let mut state = FollowState {
+				filter: UnitFilter::from_env(),
+				filter_restricted: false,
+				deadline: self.boot_timeout.map(|timeout| Instant::now() + timeout),
+				retry_delay: MIN_RETRY_DELAY,
+			};
+			loop {
+				match self.follow(sink, &mut state).await {
+					Ok(Interruption::Stopped) => return Ok(()),
+					Ok(Interruption::Resync) => log::info!("systemd reloaded, catching up on its jobs"),
+					Err(e) => {
+						log::warn!(
+							"Lost track of systemd: {e}, reconnecting in {:?}",
+							state.retry_delay
+						);
+						if !sink
+							.report(Some(ProgressError::Reconnecting(e.to_string())))
+							.await
+						{
+							return Ok(());
+						}
+						futures_util::select! {
+								_ = FutureExt::fuse(smol::Timer::after(state.retry_delay)) => {},
+								_ = sink.wait_for_shutdown().fuse() => return Ok(()),
+						}
+						state.retry_delay = (state.retry_delay * 2).min(MAX_RETRY_DELAY);
+						if self.timed_out(&state, sink) && !self.give_up_waiting(sink).await {
+							return Ok(());
+						}
+					}
+				}
+			}
+		}
+		.boxed_local()
+	}
+}
+
+impl SystemdSource {
+	/// Connect, catch up with the jobs that are already running and follow the new ones.
+	async fn follow(
+		&self,
+		sink: &mut ProgressSink,
+		state: &mut FollowState,
+	) -> color_eyre::Result<Interruption> {
+		let connection = Connection::system().await?;
+		let manager = ManagerProxy::new(&connection).await?;
+		// Subscribe before looking at the current state, so nothing happens in between unnoticed
+		let mut job_new_stream = manager.receive_job_new().await?;
+		let mut job_removed_stream = manager.receive_job_removed().await?;
+		let mut startup_finished_stream = manager.receive_startup_finished().await?;
+		let mut reloading_stream = manager.receive_reloading().await?;
+
+		if self.transition == Transition::Boot && !state.filter_restricted {
+			// Jobs that show up meanwhile wait in the streams we already subscribed to
+			match state
+				.filter
+				.restrict_to_default_target(&connection, &manager)
+				.await
+			{
+				Ok(()) => state.filter_restricted = true,
+				Err(e) => log::warn!(
+					"Failed to follow the dependencies of the default target, counting every unit: {e}"
+				),
+			}
+		}
+		if self.transition == Transition::Boot
+			&& !sink.progress().finished
+			&& default_target_is_active(&connection, &manager).await?
+			&& !sink.send(ProgressEvent::Finished).await
+		{
+			return Ok(Interruption::Stopped);
+		}
+		if !self.catch_up(sink, state, &connection, &manager).await? {
+			return Ok(Interruption::Stopped);
+		}
+		if matches!(sink.progress().error, Some(ProgressError::Reconnecting(_)))
+			&& !sink.report(None).await
+		{
+			return Ok(Interruption::Stopped);
+		}
+		state.retry_delay = MIN_RETRY_DELAY;
+
+		loop {
+			let deadline = match state.deadline {
+				Some(deadline) if !sink.progress().finished => smol::Timer::at(deadline),
+				_ => smol::Timer::never(),
+			};
+			let mut timed_out = false;
+			let event = futures_util::select! {
+					new_event = job_new_stream.next().fuse() => {
+							let Some(new_event) = new_event else {
+									return Err(eyre!("the JobNew signal stream ended"));
+							};
+							let args = match new_event.args() {
+									Ok(args) => args,
+									Err(e) => {
+											log::warn!("Failed to get JobNew event args: {e}");
+											continue;
+									}
+							};
+							ProgressEvent::UnitStarted(args.unit)
+					},
+					removed_event = job_removed_stream.next().fuse() => {
+							let Some(removed_event) = removed_event else {
+									return Err(eyre!("the JobRemoved signal stream ended"));
+							};
+							let args = match removed_event.args() {
+									Ok(args) => args,
+									Err(e) => {
+											log::warn!("Failed to get JobRemoved event args: {e}");
+											continue;
+									}
+							};
+							match args.result.as_str() {
+									"done" | "dependency" | "skipped" => ProgressEvent::UnitDone(args.unit),
+									"canceled" | "timeout" | "failed" => ProgressEvent::UnitFailed(args.unit),
+									other => {
+											log::warn!("The job of {} ended with an unknown result {other:?}", args.unit);
+											ProgressEvent::UnitUnknown(args.unit)
+									}
+							}
+					},
+					startup_finished_event = startup_finished_stream.next().fuse() => {
+							if startup_finished_event.is_none() {
+									return Err(eyre!("the StartupFinished signal stream ended"));
+							}
+							ProgressEvent::Finished
+					},
+					reloading_event = reloading_stream.next().fuse() => {
+							let Some(reloading_event) = reloading_event else {
+									return Err(eyre!("the Reloading signal stream ended"));
+							};
+							// Sent with `true` when the reload starts and `false` once it's done
+							if reloading_event.args().is_ok_and(|args| !args.active) {
+									return Ok(Interruption::Resync);
+							}
+							continue;
+					},
+					_ = FutureExt::fuse(deadline) => {
+							timed_out = true;
+							ProgressEvent::Finished
+					},
+					_ = sink.wait_for_shutdown().fuse() => return Ok(Interruption::Stopped),
+			};
+			if timed_out {
+				if !self.give_up_waiting(sink).await {
+					return Ok(Interruption::Stopped);
+				}
+				continue;
+			}
+			let keep_going = match event {
+				ProgressEvent::UnitStarted(unit) if state.filter.counts(&unit) => {
+					self.start_unit(sink, &connection, &manager, unit).await
+				}
+				ProgressEvent::UnitStarted(_) => true,
+				ProgressEvent::UnitDone(ref unit)
+				| ProgressEvent::UnitFailed(ref unit)
+				| ProgressEvent::UnitUnknown(ref unit)
+					if !state.filter.counts(unit) =>
+				{
+					true
+				}
+				event => sink.send(event).await,
+			};
+			if !keep_going {
+				return Ok(Interruption::Stopped);
+			}
+		}
+	}
+
+	/// Add the jobs we don't know about yet, and finish the ones that ended while we weren't looking.
+	///
+	/// Returns `false` once the [`super::ProgressWatcher`] is gone.
+	async fn catch_up(
+		&self,
+		sink: &mut ProgressSink,
+		state: &FollowState,
+		connection: &Connection,
+		manager: &ManagerProxy<'_>,
+	) -> zbus::Result<bool> {
+		// The stop jobs of a shutdown are all queued at once, before we could subscribe
+		let jobs = manager.list_jobs().await?;
+		let queued = jobs.iter().map(|job| job.1.clone()).collect::<HashSet<_>>();
+		for job in jobs {
+			if !state.filter.counts(&job.1) || sink.progress().services.contains_key(&job.1) {
+				continue;
+			}
+			if !self
+				.start_unit(sink, connection, manager, job.1.clone())
+				.await
+			{
+				return Ok(false);
+			}
+			// It may have been running for a while, we only know when we noticed it
+			sink.mark_caught_up(&job.1);
+		}
+		let ended = sink
+			.progress()
+			.services
+			.iter()
+			.filter(|(unit, &s)| s == ServiceState::Loading && !queued.contains(*unit))
+			.map(|(unit, _)| unit.clone())
+			.collect::<Vec<_>>();
+		for unit in ended {
+			// Units that stopped while we were away can be garbage collected, GetUnit would fail
+			// for them. Failed units are kept around, so a unit LoadUnit brings back didn't fail
+			let path = manager.load_unit(unit.clone()).await?;
+			let failed = UnitProxy::new(connection, path)
+				.await?
+				.active_state()
+				.await?
+				== "failed";
+			let event = if failed {
+				ProgressEvent::UnitFailed(unit)
+			} else {
+				ProgressEvent::UnitDone(unit)
+			};
+			if !sink.send(event).await {
+				return Ok(false);
+			}
+		}
+		Ok(true)
+	}
+
+	fn timed_out(&self, state: &FollowState, sink: &ProgressSink) -> bool {
+		!sink.progress().finished
+			&& state
+				.deadline
+				.is_some_and(|deadline| Instant::now() >= deadline)
+	}
+
+	/// Mark the boot finished when systemd never says so, so the greeter still shows up.
+	///
+	/// Returns `false` once the [`super::ProgressWatcher`] is gone.
+	async fn give_up_waiting(&self, sink: &mut ProgressSink) -> bool {
+		let timeout = self.boot_timeout.unwrap_or_default();
+		log::warn!("The boot didn't finish within {timeout:?}, marking it finished");
+		sink.report(Some(ProgressError::TimedOut(timeout))).await
+			&& sink.send(ProgressEvent::Finished).await
+	}
+
+	/// Report a new job, followed by the description and timeout of its unit for the UI.
+	async fn start_unit(
+		&self,
+		sink: &mut ProgressSink,
+		connection: &Connection,
+		manager: &ManagerProxy<'_>,
+		unit: String,
+	) -> bool {
+		if !sink.send(ProgressEvent::UnitStarted(unit.clone())).await {
+			return false;
+		}
+		match self.unit_info(connection, manager, &unit).await {
+			Ok(info) => sink.send(ProgressEvent::UnitInfo(unit, info)).await,
+			Err(e) => {
+				log::warn!("Failed to get the description of {unit}: {e}");
+				true
+			}
+		}
+	}
+
+	async fn unit_info(
+		&self,
+		connection: &Connection,
+		manager: &ManagerProxy<'_>,
+		unit: &str,
+	) -> zbus::Result<UnitInfo> {
+		let path = manager.get_unit(unit.to_string()).await?;
+		let proxy = UnitProxy::new(connection, path.clone()).await?;
+		// Like systemd does for its "A start job is running" message, the closest timeout wins
+		let mut timeouts = vec![
+			proxy.job_timeout_u_sec().await?,
+			proxy.job_running_timeout_u_sec().await?,
+		];
+		if unit.ends_with(".service") {
+			let service = ServiceProxy::new(connection, path).await?;
+			timeouts.push(match self.transition {
+				Transition::Boot => service.timeout_start_u_sec().await?,
+				Transition::Shutdown => service.timeout_stop_u_sec().await?,
+			});
+		}
+		Ok(UnitInfo {
+			description: proxy.description().await?,
+			timeout: timeouts
+				.into_iter()
+				.filter(|&usec| usec != 0 && usec != u64::MAX)
+				.min()
+				.map(Duration::from_micros),
+		})
+	}
+}
+
+async fn default_target_is_active(
+	connection: &Connection,
+	manager: &ManagerProxy<'_>,
+) -> zbus::Result<bool> {
+	let default_target_path = manager
+		.get_unit(manager.get_default_target().await?)
+		.await?;
+	let default_target = UnitProxy::new(connection, default_target_path).await?;
+	Ok(default_target.active_state().await? == "active")
+}
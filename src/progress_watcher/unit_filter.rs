@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use zbus_systemd::systemd1::{ManagerProxy, UnitProxy};
+use zbus_systemd::zbus::{self, Connection};
+
+/// Decides which jobs count for the progress, so timers and units unrelated to the boot don't add noise.
+#[derive(Debug, Clone, Default)]
+pub struct UnitFilter {
+	/// Units the default target pulls in or waits for, every unit counts if this is `None`
+	relevant: Option<HashSet<String>>,
+	/// Patterns like `*.timer` from `TIBS_PROGRESS_IGNORE`, separated by commas or spaces
+	ignored: Vec<String>,
+}
+
+impl UnitFilter {
+	pub fn from_env() -> Self {
+		let ignored = std::env::var("TIBS_PROGRESS_IGNORE")
+			.unwrap_or_default()
+			.split(|c: char| c == ',' || c.is_whitespace())
+			.filter(|pattern| !pattern.is_empty())
+			.map(str::to_string)
+			.collect();
+		Self {
+			relevant: None,
+			ignored,
+		}
+	}
+
+	/// Only count the units in the dependency tree of the default target.
+	pub async fn restrict_to_default_target(
+		&mut self,
+		connection: &Connection,
+		manager: &ManagerProxy<'_>,
+	) -> zbus::Result<()> {
+		let default_target = manager.get_default_target().await?;
+		let mut relevant = HashSet::new();
+		let mut queue = vec![default_target];
+		while let Some(unit) = queue.pop() {
+			if !relevant.insert(unit.clone()) {
+				continue;
+			}
+			// Units that aren't loaded can't have jobs anyway
+			let Ok(path) = manager.get_unit(unit).await else {
+				continue;
+			};
+			let unit = UnitProxy::new(connection, path).await?;
+			for dependencies in [
+				unit.wants().await?,
+				unit.requires().await?,
+				unit.after().await?,
+			] {
+				queue.extend(
+					dependencies
+						.into_iter()
+						.filter(|dependency| !relevant.contains(dependency)),
+				);
+			}
+		}
+		log::info!(
+			"Counting {} units from the default target for the progress",
+			relevant.len()
+		);
+		self.relevant = Some(relevant);
+		Ok(())
+	}
+
+	pub fn counts(&self, unit: &str) -> bool {
+		self
+			.relevant
+			.as_ref()
+			.is_none_or(|relevant| relevant.contains(unit))
+			&& !self
+				.ignored
+				.iter()
+				.any(|pattern| wildcard_match(pattern, unit))
+	}
+}
+
+/// Shell-like matching where `*` is any run of characters and `?` is any single character.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+	let pattern = pattern.chars().collect::<Vec<_>>();
+	let text = text.chars().collect::<Vec<_>>();
+	let (mut p, mut t) = (0, 0);
+	// Where to resume after the last `*` if the rest doesn't match
+	let mut backtrack = None;
+	while t < text.len() {
+		match pattern.get(p) {
+			Some('*') => {
+				backtrack = Some((p, t));
+				p += 1;
+			}
+			Some(&c) if c == '?' || c == text[t] => {
+				p += 1;
+				t += 1;
+			}
+			_ => match backtrack {
+				Some((star, star_t)) => {
+					p = star + 1;
+					t = star_t + 1;
+					backtrack = Some((star, star_t + 1));
+				}
+				None => return false,
+			},
+		}
+	}
+	pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_wildcard_match() {
+		assert!(wildcard_match("*.timer", "logrotate.timer"));
+		assert!(!wildcard_match("*.timer", "logrotate.service"));
+		assert!(wildcard_match(
+			"systemd-*-setup?.service",
+			"systemd-tmpfiles-setup2.service"
+		));
+		assert!(wildcard_match("*", ""));
+		assert!(!wildcard_match("foo", "foobar"));
+		assert!(wildcard_match("*a*b", "xaxxab"));
+	}
+}
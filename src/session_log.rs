@@ -0,0 +1,80 @@
+use nix::libc;
+use std::{
+	fs::OpenOptions,
+	io::{self, Read, Seek, SeekFrom},
+	os::unix::fs::{MetadataExt, OpenOptionsExt},
+	path::{Path, PathBuf},
+};
+use uzers::os::unix::UserExt;
+
+/// How much of the end of the log is read to find the last lines
+const TAIL_READ_SIZE: u64 = 64 * 1024;
+
+/// Shell snippet that rotates the log at `$TIBS_SESSION_LOG` and sends the rest of the
+/// script's output to it.
+///
+/// This runs as the user, so tibs never writes into a directory the user controls.
+pub const REDIRECT_OUTPUT_SCRIPT: &str = r#"
+if [ -n "$TIBS_SESSION_LOG" ] && mkdir -p "${TIBS_SESSION_LOG%/*}"; then
+	[ -e "$TIBS_SESSION_LOG" ] && mv -f "$TIBS_SESSION_LOG" "$TIBS_SESSION_LOG.old"
+	exec >"$TIBS_SESSION_LOG" 2>&1
+fi
+unset TIBS_SESSION_LOG
+"#;
+
+/// The output of a session, written to `~/.local/share/tibs/session.log`.
+///
+/// The log of the previous session is kept in `session.log.old`.
+pub struct SessionLog {
+	uid: u32,
+	path: PathBuf,
+}
+
+impl SessionLog {
+	pub fn for_user(uid: u32) -> Option<Self> {
+		let user = uzers::get_user_by_uid(uid)?;
+		Some(Self {
+			uid,
+			path: user.home_dir().join(".local/share/tibs/session.log"),
+		})
+	}
+
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// Read the last `count` lines of the log.
+	///
+	/// Fails if the log isn't a regular file owned by the user, so a symlink can't be
+	/// used to show other files on the greeter.
+	pub fn tail(&self, count: usize) -> io::Result<Vec<String>> {
+		let mut file = OpenOptions::new()
+			.read(true)
+			.custom_flags(libc::O_NOFOLLOW | libc::O_NONBLOCK)
+			.open(&self.path)?;
+		let metadata = file.metadata()?;
+		if !metadata.is_file() || metadata.uid() != self.uid {
+			return Err(io::Error::new(
+				io::ErrorKind::PermissionDenied,
+				format!("{} is not a log file owned by the user", self.path.display()),
+			));
+		}
+		let start = metadata.len().saturating_sub(TAIL_READ_SIZE);
+		file.seek(SeekFrom::Start(start))?;
+		let mut bytes = Vec::new();
+		file.read_to_end(&mut bytes)?;
+		let text = String::from_utf8_lossy(&bytes);
+		let mut lines = text.lines();
+		if start > 0 {
+			// The first line was cut in the middle
+			lines.next();
+		}
+		let lines = lines.collect::<Vec<_>>();
+		Ok(
+			lines[lines.len().saturating_sub(count)..]
+				.iter()
+				.map(|l| l.to_string())
+				.collect(),
+		)
+	}
+}
@@ -1,14 +1,17 @@
 use crate::login::LoginManager;
+use crate::login_records;
+use crate::session_log::{SessionLog, REDIRECT_OUTPUT_SCRIPT};
+use crate::session_scope::SessionScope;
+use crate::session_supervisor::{ExitReason, ProcessExit, SessionSupervisor};
 use crate::tty::*;
 use color_eyre::eyre::bail;
 use color_eyre::eyre::OptionExt;
 use freedesktop_entry_parser::parse_entry;
 use nix::libc;
 use nix::libc::*;
-use rustamarine::Rustamarine;
-use std::cell::RefCell;
+use rustamarine::{Rustamarine, Waker};
+use std::cell::Cell;
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::env;
 use std::ffi::CStr;
 use std::fs;
@@ -17,13 +20,34 @@ use std::fs::OpenOptions;
 use std::mem::MaybeUninit;
 use std::os::fd::AsRawFd;
 use std::os::unix::process::CommandExt;
-use std::process::Child;
 use std::process::Command;
+use std::path::Path;
 use std::rc::Rc;
-#[derive(Debug)]
+use uzers::os::unix::UserExt;
+use std::time::{Duration, Instant};
+#[derive(Debug, Clone)]
 pub struct DesktopEnvironmentFile {
 	name: String,
 	command: String,
+	/// Runs on the text console instead of starting a graphical session
+	terminal: bool,
+}
+impl DesktopEnvironmentFile {
+	/// A login shell on the session's VT, for when the graphical sessions are broken.
+	pub fn failsafe(shell: &Path) -> Self {
+		Self {
+			name: "Failsafe terminal".into(),
+			command: format!("exec {} -l", shell_quote(&shell.to_string_lossy())),
+			terminal: true,
+		}
+	}
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+}
+/// Quote a string so the shell running the session command takes it literally.
+fn shell_quote(value: &str) -> String {
+	format!("'{}'", value.replace('\'', r"'\''"))
 }
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum SessionStatus {
@@ -32,46 +56,164 @@ pub enum SessionStatus {
 	Crashed,
 }
 pub struct Session {
-	process: RefCell<Child>,
+	pid: u32,
 	tty: TTYInfo,
 	user_id: u32,
+	/// Set by the [`SessionManager`] once the supervisor reaps the process
+	exit_reason: Cell<Option<ExitReason>>,
+	/// `None` if the process exited before it could be moved into a scope
+	scope: Option<SessionScope>,
+	log: Option<SessionLog>,
+	session_file: DesktopEnvironmentFile,
+	started_at: Instant,
+	exited_at: Cell<Option<Instant>>,
+}
+/// A session process that went away, reported by [`SessionManager::poll_session_exits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionExit {
+	pub uid: u32,
+	pub reason: ExitReason,
 }
 
 impl Session {
 	fn new(
+		id: u32,
 		uid: u32,
 		tty: TTYInfo,
 		session_file: &DesktopEnvironmentFile,
-		rmar: &mut Rustamarine,
+		rmar: &Rustamarine,
+		supervisor: &SessionSupervisor,
 	) -> color_eyre::Result<Session> {
-		tty.make_current(rmar);
-		let process = RefCell::new(
-			Command::new("bash")
-				.uid(uid)
-				.args(["-c", &session_file.command])
-				.spawn()?,
-		);
+		tty.reset()?;
+		tty.make_current(rmar)?;
+		let user = uzers::get_user_by_uid(uid).ok_or_eyre("The session's user doesn't exist")?;
+		// Terminal sessions print to the VT, so there's nothing to log
+		let log = (!session_file.terminal)
+			.then(|| SessionLog::for_user(uid))
+			.flatten();
+		// The shell stops itself until it's inside its scope, so the session can't
+		// fork anything outside of it
+		let script = format!("kill -STOP $$\n{REDIRECT_OUTPUT_SCRIPT}\neval \"$0\"");
+		let mut command = Command::new("bash");
+		command
+			.uid(uid)
+			.args(["-c", &script, &session_file.command])
+			.env("HOME", user.home_dir())
+			.env("SHELL", user.shell())
+			.env("USER", user.name())
+			.env("LOGNAME", user.name());
+		if session_file.terminal {
+			command
+				.stdin(tty.fd.try_clone()?)
+				.stdout(tty.fd.try_clone()?)
+				.stderr(tty.fd.try_clone()?);
+			// Make the VT the controlling terminal of the shell, so job control and ^C work
+			unsafe {
+				command.pre_exec(|| {
+					if libc::setsid() < 0 || libc::ioctl(0, libc::TIOCSCTTY, 0) < 0 {
+						return Err(std::io::Error::last_os_error());
+					}
+					Ok(())
+				})
+			};
+		}
+		if let Some(log) = &log {
+			command.env("TIBS_SESSION_LOG", log.path());
+		}
+		let process = command.spawn()?;
+		// The supervisor reaps the process, so the Child handle must not be waited on
+		let pid = process.id();
+		let mut status = 0;
+		let stopped = loop {
+			if unsafe { libc::waitpid(pid as libc::pid_t, &mut status, libc::WUNTRACED) } >= 0 {
+				break libc::WIFSTOPPED(status);
+			}
+			let error = std::io::Error::last_os_error();
+			if error.kind() != std::io::ErrorKind::Interrupted {
+				// Not reaped, so the pid can't have been reused
+				unsafe { libc::kill(pid as libc::pid_t, libc::SIGKILL) };
+				supervisor.watch(pid);
+				return Err(error.into());
+			}
+		};
+		let (scope, early_exit) = if stopped {
+			// Continues the process once it's inside the scope
+			let scope = SessionScope::start(id, pid, uid, format!("Session {}", session_file.name));
+			supervisor.watch(pid);
+			(Some(scope), None)
+		} else {
+			// The shell died before stopping itself and waitpid already reaped it
+			let reason = ExitReason::from_wait_status(status);
+			supervisor.report_exit(ProcessExit { pid, reason });
+			(None, Some(reason))
+		};
+		login_records::record_login(&user.name().to_string_lossy(), uid, pid, tty.number);
 		Ok(Self {
-			process,
+			pid,
 			tty,
 			user_id: uid,
+			exit_reason: Cell::new(early_exit),
+			scope,
+			log,
+			session_file: session_file.clone(),
+			started_at: Instant::now(),
+			exited_at: Cell::new(None),
 		})
 	}
+	pub fn user_id(&self) -> u32 {
+		self.user_id
+	}
+	pub fn tty_number(&self) -> u16 {
+		self.tty.number
+	}
+	/// Switch to the VT the session is running on.
+	pub fn switch_to(&self, rmar: &Rustamarine) -> std::io::Result<()> {
+		self.tty.make_current(rmar)
+	}
+	pub fn session_file(&self) -> &DesktopEnvironmentFile {
+		&self.session_file
+	}
+	/// How long the session ran for, or has been running for if it's still alive.
+	pub fn runtime(&self) -> Duration {
+		self
+			.exited_at
+			.get()
+			.unwrap_or_else(Instant::now)
+			.duration_since(self.started_at)
+	}
+	/// The last `count` lines the session printed, oldest first.
+	pub fn log_tail(&self, count: usize) -> Vec<String> {
+		let Some(log) = &self.log else {
+			return Vec::new();
+		};
+		log.tail(count).unwrap_or_else(|e| {
+			log::warn!("Failed to read {}: {e}", log.path().display());
+			Vec::new()
+		})
+	}
+	/// Terminate every process of the session.
+	pub fn stop(&self) {
+		if let Some(scope) = &self.scope {
+			scope.stop(self.status() == SessionStatus::Running);
+		}
+	}
 	pub fn status(&self) -> SessionStatus {
-		match self.process.borrow_mut().try_wait() {
-			Ok(Some(code)) if code.success() => SessionStatus::ShutdownGracefully,
-			Ok(Some(_)) => SessionStatus::Crashed,
-			Ok(None) => SessionStatus::Running,
-			Err(_) => SessionStatus::Crashed,
+		match self.exit_reason.get() {
+			None => SessionStatus::Running,
+			Some(reason) if reason.is_success() => SessionStatus::ShutdownGracefully,
+			Some(_) => SessionStatus::Crashed,
 		}
 	}
+	pub fn exit_reason(&self) -> Option<ExitReason> {
+		self.exit_reason.get()
+	}
 }
 
 impl Drop for Session {
 	fn drop(&mut self) {
 		match self.status() {
 			SessionStatus::Running => {
-				self.process.borrow_mut().kill().ok();
+				self.stop();
 				let current_tty = TTYInfo::get_active_tty_number();
 				if self.tty.number == current_tty {
 					println!("[WARN] Dropped session while still inside the session's tty: {current_tty}");
@@ -84,7 +226,10 @@ impl Drop for Session {
 
 pub struct SessionManager {
 	sessions: HashMap<u32, Rc<Session>>,
-	tibs_tty: u16,
+	next_session_id: u32,
+	supervisor: SessionSupervisor,
+	/// The VT tibs itself runs on, where users are sent back to when their session ends
+	greeter_tty: u16,
 	wayland_desktop_environments_cache: Vec<DesktopEnvironmentFile>,
 }
 
@@ -116,7 +261,11 @@ impl SessionManager {
 				let section = entry.section("Desktop Entry");
 				let name = section.attr("Name")?.to_string();
 				let command = section.attr("Exec")?.to_string();
-				Some(DesktopEnvironmentFile { name, command })
+				Some(DesktopEnvironmentFile {
+					name,
+					command,
+					terminal: false,
+				})
 			})
 			.collect()
 	}
@@ -126,45 +275,104 @@ impl SessionManager {
 	pub fn get_desktop_environments_list(&self) -> &[DesktopEnvironmentFile] {
 		&self.wayland_desktop_environments_cache
 	}
-	pub fn new() -> Self {
+	pub fn new(waker: Waker) -> Self {
 		Self {
 			sessions: Default::default(),
-			tibs_tty: TTYInfo::get_active_tty_number(),
+			next_session_id: 1,
+			supervisor: SessionSupervisor::new(waker),
+			greeter_tty: TTYInfo::get_active_tty_number(),
 			wayland_desktop_environments_cache: Self::discover_wayland_desktop_environments(),
 		}
 	}
 
-	fn next_tty(&self) -> Option<TTYInfo> {
-		let used_ttys = self
-			.sessions
-			.values()
-			.filter(|s| matches!(s.status(), SessionStatus::Running))
-			.map(|s| s.tty.number)
-			.collect::<HashSet<_>>();
-		(0..63u16)
-			.into_iter()
-			.find_map(|i| (i != self.tibs_tty && !used_ttys.contains(&i)).then(|| TTYInfo::new(i)))
-			.flatten()
-	}
 	pub fn start_session(
 		&mut self,
 		login_manager: &LoginManager,
 		username: &str,
 		session_file: &DesktopEnvironmentFile,
-		rmar: &mut Rustamarine,
+		rmar: &Rustamarine,
 	) -> color_eyre::Result<Rc<Session>> {
 		let Some(crate::login::LoginState::Authenticated(uid)) =
 			login_manager.get_current_login_state(username)
 		else {
 			bail!("Tried to start session without being authenticated (user={username})");
 		};
-		let free_tty = self
-			.next_tty()
-			.ok_or_eyre("There's no free tty's left for this session.")?;
-		let session = Session::new(uid, free_tty, session_file, rmar).map(Rc::new)?;
+		// Replacing the session would drop it, killing everything the user had open
+		if self.is_running(uid) {
+			bail!("{username} already has a running session");
+		}
+		// Running sessions keep their VT open, so the kernel won't hand it out again
+		let free_tty = TTYInfo::allocate()?;
+		let id = self.next_session_id;
+		self.next_session_id += 1;
+		let session = Session::new(id, uid, free_tty, session_file, rmar, &self.supervisor)
+			.map(Rc::new)?;
 		self.sessions.insert(uid, Rc::clone(&session));
 		Ok(session)
 	}
+	/// Handle the sessions that exited since the last call.
+	///
+	/// Records why each session exited, and switches back to the greeter if the
+	/// user was looking at the session that went away.
+	pub fn poll_session_exits(&mut self, rmar: &Rustamarine) -> Vec<SessionExit> {
+		let mut exits = Vec::new();
+		while let Some(exit) = self.supervisor.poll_exit() {
+			let Some(session) = self.sessions.values().find(|s| s.pid == exit.pid) else {
+				continue;
+			};
+			session.exit_reason.set(Some(exit.reason));
+			session.exited_at.set(Some(Instant::now()));
+			login_records::record_logout(session.pid, session.tty.number);
+			log::info!(
+				"Session of user {} on tty{} {}",
+				session.user_id,
+				session.tty.number,
+				exit.reason
+			);
+			// Logging out means the main process exits, take everything else with it
+			if let Some(scope) = &session.scope {
+				scope.stop(false);
+			}
+			if TTYInfo::get_active_tty_number() == session.tty.number {
+				if let Err(e) = self.switch_to_greeter(rmar) {
+					log::warn!("Failed to switch back to the greeter: {e}");
+				}
+			}
+			exits.push(SessionExit {
+				uid: session.user_id,
+				reason: exit.reason,
+			});
+		}
+		exits
+	}
+	/// Switch to the VT tibs runs on, leaving every session running.
+	pub fn switch_to_greeter(&self, rmar: &Rustamarine) -> std::io::Result<()> {
+		if TTYInfo::get_active_tty_number() == self.greeter_tty {
+			return Ok(());
+		}
+		let tty = TTYInfo::new(self.greeter_tty).ok_or_else(std::io::Error::last_os_error)?;
+		if rmar.is_session_active() {
+			tty.make_current(rmar)
+		} else {
+			// The seat belongs to the session we're switching away from, so only the
+			// kernel can switch us back
+			tty.activate()
+		}
+	}
+	pub fn running_sessions(&self) -> impl Iterator<Item = &Rc<Session>> {
+		self
+			.sessions
+			.values()
+			.filter(|s| s.status() == SessionStatus::Running)
+	}
+	/// Get the running session on the VT that is currently shown, if it's one of ours.
+	pub fn active_session(&self) -> Option<&Rc<Session>> {
+		let active_tty = TTYInfo::get_active_tty_number();
+		self.running_sessions().find(|s| s.tty.number == active_tty)
+	}
+	pub fn get_session_of_user(&self, uid: u32) -> Option<Rc<Session>> {
+		self.sessions.get(&uid).cloned()
+	}
 	pub fn get_session_state_of_user(&self, uid: u32) -> Option<SessionStatus> {
 		self.sessions.get(&uid).map(|s| s.status())
 	}
@@ -175,3 +383,16 @@ impl SessionManager {
 		self.sessions.get(&uid).is_some_and(|s| matches!(s.status(), SessionStatus::Crashed))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_failsafe_command() {
+		let failsafe = DesktopEnvironmentFile::failsafe(Path::new("/bin/bash"));
+		assert_eq!(failsafe.command, "exec '/bin/bash' -l");
+		let failsafe = DesktopEnvironmentFile::failsafe(Path::new("/opt/it's/sh"));
+		assert_eq!(failsafe.command, r"exec '/opt/it'\''s/sh' -l");
+	}
+}
@@ -0,0 +1,169 @@
+use nix::libc;
+use smol::channel;
+use std::{
+	os::fd::{AsRawFd, OwnedFd},
+	thread::{self, JoinHandle},
+	time::Duration,
+};
+use zbus_systemd::systemd1::ManagerProxy;
+use zbus_systemd::zbus::{
+	self,
+	zvariant::{OwnedValue, Value},
+	Connection,
+};
+
+use crate::session_supervisor::pidfd_open;
+
+const DEFAULT_STOP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A transient systemd scope holding every process of a session.
+///
+/// Stopping the scope sends SIGTERM to the whole process tree, and SIGKILL to whatever
+/// is still alive after the stop timeout, so nothing the session started survives logout.
+///
+/// Talking to systemd happens on a thread per session, so the UI never waits on D-Bus.
+pub struct SessionScope {
+	/// Stop requests, with whether the main process should be killed if there's no scope
+	stop_tx: Option<channel::Sender<bool>>,
+	worker: Option<JoinHandle<()>>,
+}
+
+impl SessionScope {
+	/// Move a stopped process into a new scope in the user's slice, then let it continue.
+	///
+	/// The process is continued even if the scope can't be created. The units are called
+	/// `tibs-session-N.scope`, so they don't clash with the `session-N.scope` units logind
+	/// creates for its own sessions.
+	pub fn start(id: u32, pid: u32, uid: u32, description: String) -> Self {
+		// Opened while the process is stopped and not reaped, so it's the right process
+		let process = Process {
+			pid,
+			pidfd: pidfd_open(pid).ok(),
+		};
+		let (stop_tx, stop_rx) = channel::unbounded();
+		let worker = thread::spawn(move || {
+			let name = format!("tibs-session-{id}.scope");
+			let scope = smol::block_on(create_scope(&name, pid, uid, &description))
+				.inspect_err(|e| log::warn!("Failed to create {name}: {e}"))
+				.ok();
+			process.signal(libc::SIGCONT);
+			// Closed once the session is dropped
+			while let Ok(kill_main_process) = stop_rx.recv_blocking() {
+				if let Some(connection) = &scope {
+					match smol::block_on(stop_scope(connection, &name)) {
+						Ok(()) => continue,
+						Err(e) => log::warn!("Failed to stop {name}: {e}"),
+					}
+				}
+				if kill_main_process {
+					process.signal(libc::SIGKILL);
+				}
+			}
+		});
+		Self {
+			stop_tx: Some(stop_tx),
+			worker: Some(worker),
+		}
+	}
+
+	/// Stop every process in the scope, waiting up to the stop timeout before killing them.
+	///
+	/// Without a scope only the main process can be killed, pass `kill_main_process` as long
+	/// as it wasn't reaped.
+	pub fn stop(&self, kill_main_process: bool) {
+		if let Some(stop_tx) = &self.stop_tx {
+			let _ = stop_tx.try_send(kill_main_process);
+		}
+	}
+}
+
+impl Drop for SessionScope {
+	fn drop(&mut self) {
+		// Running sessions are only dropped when tibs exits, let their stop go through first
+		self.stop_tx.take();
+		if let Some(worker) = self.worker.take() {
+			let _ = worker.join();
+		}
+	}
+}
+
+struct Process {
+	pid: u32,
+	/// `None` on kernels without pidfds
+	pidfd: Option<OwnedFd>,
+}
+
+impl Process {
+	fn signal(&self, signal: libc::c_int) {
+		match &self.pidfd {
+			// Fails harmlessly once the process was reaped, instead of hitting a reused pid
+			Some(pidfd) => unsafe {
+				libc::syscall(
+					libc::SYS_pidfd_send_signal,
+					pidfd.as_raw_fd(),
+					signal,
+					std::ptr::null::<libc::siginfo_t>(),
+					0,
+				);
+			},
+			None => unsafe {
+				libc::kill(self.pid as libc::pid_t, signal);
+			},
+		}
+	}
+}
+
+async fn create_scope(
+	name: &str,
+	pid: u32,
+	uid: u32,
+	description: &str,
+) -> zbus::Result<Connection> {
+	let connection = Connection::system().await?;
+	let manager = ManagerProxy::new(&connection).await?;
+	let properties = vec![
+		("Description".to_string(), owned(description)),
+		("Slice".to_string(), owned(format!("user-{uid}.slice"))),
+		("PIDs".to_string(), owned(vec![pid])),
+		(
+			"TimeoutStopUSec".to_string(),
+			owned(stop_timeout().as_micros() as u64),
+		),
+		("SendSIGHUP".to_string(), owned(true)),
+	];
+	manager
+		.start_transient_unit(name.to_string(), "fail".into(), properties, vec![])
+		.await?;
+	Ok(connection)
+}
+
+async fn stop_scope(connection: &Connection, name: &str) -> zbus::Result<()> {
+	let manager = ManagerProxy::new(connection).await?;
+	match manager.stop_unit(name.to_string(), "replace".into()).await {
+		// Empty scopes are garbage collected as soon as their last process exits
+		Err(zbus::Error::MethodError(error, _, _))
+			if error.as_str() == "org.freedesktop.systemd1.NoSuchUnit" =>
+		{
+			Ok(())
+		}
+		Err(e) => Err(e),
+		Ok(_) => Ok(()),
+	}
+}
+
+/// How long session processes get to exit after SIGTERM, from `TIBS_SESSION_STOP_TIMEOUT` (seconds).
+fn stop_timeout() -> Duration {
+	std::env::var("TIBS_SESSION_STOP_TIMEOUT")
+		.ok()
+		.and_then(|s| s.trim().parse::<f64>().ok())
+		.filter(|s| *s >= 0.0)
+		.map(Duration::from_secs_f64)
+		.unwrap_or(DEFAULT_STOP_TIMEOUT)
+}
+
+fn owned<'a>(value: impl Into<Value<'a>>) -> OwnedValue {
+	value
+		.into()
+		.try_into()
+		.expect("Values without file descriptors can always be owned")
+}
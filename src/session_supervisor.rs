@@ -0,0 +1,230 @@
+use nix::libc;
+use rustamarine::Waker;
+use smol::{channel, Async};
+use std::{
+	ffi::CStr,
+	fmt, io,
+	mem::MaybeUninit,
+	os::fd::{AsRawFd, FromRawFd, OwnedFd},
+	thread,
+};
+
+/// Why a session process went away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitReason {
+	Exited(i32),
+	Signaled { signal: i32, core_dumped: bool },
+}
+
+impl ExitReason {
+	/// Decode a status from `waitpid`, which must be for a process that exited.
+	pub fn from_wait_status(status: libc::c_int) -> Self {
+		if libc::WIFEXITED(status) {
+			ExitReason::Exited(libc::WEXITSTATUS(status))
+		} else {
+			ExitReason::Signaled {
+				signal: libc::WTERMSIG(status),
+				core_dumped: libc::WCOREDUMP(status),
+			}
+		}
+	}
+
+	/// Decode the `si_code` and `si_status` of a child's `siginfo_t`, as filled by `waitid`.
+	pub fn from_child_code(code: libc::c_int, status: libc::c_int) -> Self {
+		match code {
+			libc::CLD_EXITED => ExitReason::Exited(status),
+			_ => ExitReason::Signaled {
+				signal: status,
+				core_dumped: code == libc::CLD_DUMPED,
+			},
+		}
+	}
+
+	pub fn is_success(&self) -> bool {
+		matches!(self, ExitReason::Exited(0))
+	}
+}
+
+impl fmt::Display for ExitReason {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ExitReason::Exited(code) => write!(f, "exited with code {code}"),
+			ExitReason::Signaled {
+				signal,
+				core_dumped,
+			} => {
+				let description = unsafe { CStr::from_ptr(libc::strsignal(*signal)) };
+				write!(
+					f,
+					"killed by signal {signal} ({})",
+					description.to_string_lossy()
+				)?;
+				if *core_dumped {
+					write!(f, " (core dumped)")?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessExit {
+	pub pid: u32,
+	pub reason: ExitReason,
+}
+
+/// Reaps session processes in the background and reports their exits to the main loop.
+///
+/// Every watched process gets a task on a smol executor that waits on its pidfd,
+/// so exits are noticed as soon as they happen instead of when the UI asks. The main
+/// loop is woken up for every exit, since it's blocked while we're on another VT.
+pub struct SessionSupervisor {
+	watch_tx: channel::Sender<u32>,
+	exit_tx: channel::Sender<ProcessExit>,
+	exit_rx: channel::Receiver<ProcessExit>,
+}
+
+impl SessionSupervisor {
+	pub fn new(waker: Waker) -> Self {
+		let (watch_tx, watch_rx) = channel::unbounded::<u32>();
+		let (exit_tx, exit_rx) = channel::unbounded::<ProcessExit>();
+		let supervisor = Self {
+			watch_tx,
+			exit_tx: exit_tx.clone(),
+			exit_rx,
+		};
+		thread::spawn(move || {
+			let executor = smol::LocalExecutor::new();
+			smol::block_on(executor.run(async {
+				// Stops once the supervisor is dropped
+				while let Ok(pid) = watch_rx.recv().await {
+					let exit_tx = exit_tx.clone();
+					let waker = waker.clone();
+					executor
+						.spawn(async move {
+							match wait_for_exit(pid).await {
+								Ok(reason) => {
+									let _ = exit_tx.send(ProcessExit { pid, reason }).await;
+									waker.wake();
+								}
+								Err(e) => log::error!("Failed to wait for session process {pid}: {e}"),
+							}
+						})
+						.detach();
+				}
+			}));
+		});
+		supervisor
+	}
+
+	/// Start watching a child process. The process must not be waited on by anyone else.
+	pub fn watch(&self, pid: u32) {
+		let _ = self.watch_tx.try_send(pid);
+	}
+
+	/// Report the exit of a process that was reaped before it could be watched, as if it was.
+	pub fn report_exit(&self, exit: ProcessExit) {
+		let _ = self.exit_tx.try_send(exit);
+	}
+
+	/// Get the next process that exited since the last call.
+	pub fn poll_exit(&self) -> Option<ProcessExit> {
+		self.exit_rx.try_recv().ok()
+	}
+}
+
+async fn wait_for_exit(pid: u32) -> io::Result<ExitReason> {
+	match pidfd_open(pid) {
+		Ok(pidfd) => {
+			// A pidfd becomes readable when the process exits
+			let pidfd = Async::new(pidfd)?;
+			pidfd.readable().await?;
+			waitid(libc::P_PIDFD, pidfd.as_raw_fd() as libc::id_t)
+		}
+		// Kernels older than 5.3 don't have pidfds, block a thread on the pid instead
+		Err(e) if e.raw_os_error() == Some(libc::ENOSYS) => {
+			smol::unblock(move || waitid(libc::P_PID, pid as libc::id_t)).await
+		}
+		Err(e) => Err(e),
+	}
+}
+
+pub(crate) fn pidfd_open(pid: u32) -> io::Result<OwnedFd> {
+	let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as libc::pid_t, 0) };
+	if fd < 0 {
+		return Err(io::Error::last_os_error());
+	}
+	Ok(unsafe { OwnedFd::from_raw_fd(fd as libc::c_int) })
+}
+
+/// Reap an exited child and decode why it exited.
+fn waitid(id_type: libc::idtype_t, id: libc::id_t) -> io::Result<ExitReason> {
+	let mut info = MaybeUninit::<libc::siginfo_t>::zeroed();
+	if unsafe { libc::waitid(id_type, id, info.as_mut_ptr(), libc::WEXITED) } < 0 {
+		return Err(io::Error::last_os_error());
+	}
+	let info = unsafe { info.assume_init() };
+	let status = unsafe { info.si_status() };
+	Ok(ExitReason::from_child_code(info.si_code, status))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_wait_status() {
+		assert_eq!(ExitReason::from_wait_status(0), ExitReason::Exited(0));
+		assert_eq!(ExitReason::from_wait_status(3 << 8), ExitReason::Exited(3));
+		assert_eq!(
+			ExitReason::from_wait_status(libc::SIGKILL),
+			ExitReason::Signaled {
+				signal: libc::SIGKILL,
+				core_dumped: false
+			}
+		);
+		assert_eq!(
+			ExitReason::from_wait_status(libc::SIGSEGV | 0x80),
+			ExitReason::Signaled {
+				signal: libc::SIGSEGV,
+				core_dumped: true
+			}
+		);
+	}
+
+	#[test]
+	fn test_child_code() {
+		assert!(ExitReason::from_child_code(libc::CLD_EXITED, 0).is_success());
+		assert_eq!(
+			ExitReason::from_child_code(libc::CLD_EXITED, 1),
+			ExitReason::Exited(1)
+		);
+		assert_eq!(
+			ExitReason::from_child_code(libc::CLD_KILLED, libc::SIGTERM),
+			ExitReason::Signaled {
+				signal: libc::SIGTERM,
+				core_dumped: false
+			}
+		);
+		assert_eq!(
+			ExitReason::from_child_code(libc::CLD_DUMPED, libc::SIGABRT),
+			ExitReason::Signaled {
+				signal: libc::SIGABRT,
+				core_dumped: true
+			}
+		);
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(ExitReason::Exited(2).to_string(), "exited with code 2");
+		let killed = ExitReason::Signaled {
+			signal: libc::SIGSEGV,
+			core_dumped: true,
+		}
+		.to_string();
+		assert!(killed.starts_with("killed by signal 11 ("));
+		assert!(killed.ends_with(" (core dumped)"));
+	}
+}
@@ -5,6 +5,7 @@ use clay_layout::{
 	text::TextConfig,
 	Clay, Declaration,
 };
+use crate::pointer::Pointer;
 use rustamarine::keys;
 use rustamarine::Rustamarine;
 
@@ -16,6 +17,8 @@ pub struct Textbox {
 	id: String,
 	pub hide_input: bool,
 	pub disabled: bool,
+	/// Draws a warning border around the textbox, e.g. when Caps Lock is on
+	pub warning: bool,
 }
 
 impl Textbox {
@@ -28,6 +31,7 @@ impl Textbox {
 			id: id.into(),
 			hide_input,
 			disabled: false,
+			warning: false,
 		}
 	}
 	fn chars_count(s: &str) -> usize {
@@ -75,9 +79,9 @@ impl Textbox {
 			unsafe { (*textbox_scroll.scrollPosition).x = -(x_position_cursor - view_width + 24.) };
 		}
 	}
-	fn handle_mouse_clicks(&mut self, rmar: &Rustamarine, c: &mut clay_layout::Clay) {
+	fn handle_mouse_clicks(&mut self, pointer: &Pointer, c: &mut clay_layout::Clay) {
 		let id = c.id(&self.id);
-		if !rmar.is_mouse_button_pressed(0) || !c.pointer_over(id) {
+		if !pointer.pressed || !c.pointer_over(id) {
 			return;
 		}
 
@@ -98,7 +102,7 @@ impl Textbox {
 		let text_config = Self::text_config();
 		let measure_text = create_measure_text_function(&FONTS);
 
-		let click_x = rmar.get_mouse_x() as f32;
+		let click_x = pointer.x;
 		let relative_x = click_x - bounding_box.x - unsafe { (*textbox_scroll.scrollPosition).x } - 15.;
 
 		let mut best_index = 0;
@@ -132,22 +136,21 @@ impl Textbox {
 			.alignment(clay_layout::text::TextAlignment::Left);
 		return config;
 	}
-	pub fn update<'clay, 'render>(&mut self, rmar: &Rustamarine, c: &mut clay_layout::Clay)
-	where
+	pub fn update<'clay, 'render>(
+		&mut self,
+		rmar: &Rustamarine,
+		pointer: &Pointer,
+		c: &mut clay_layout::Clay,
+	) where
 		'clay: 'render,
 	{
 		if !self.focused || self.disabled {
 			return;
 		}
-		self.handle_mouse_clicks(rmar, c);
+		self.handle_mouse_clicks(pointer, c);
 		let chars_count = Self::chars_count(&self.buffer);
 		if rmar.is_key_pressed(keys::KEY_BackSpace) {
-			if self.cursor > 0 {
-				let cursor_byte_index = Self::char_index_to_byte_index(&self.buffer, self.cursor - 1);
-				self.cursor -= 1;
-				self.buffer.remove(cursor_byte_index);
-			}
-			self.scroll_cursor_into_view(c);
+			self.backspace(c);
 		} else if rmar.is_key_pressed(keys::KEY_Left) {
 			if self.cursor > 0 {
 				self.cursor -= 1;
@@ -162,6 +165,7 @@ impl Textbox {
 			let cursor_byte_index = Self::char_index_to_byte_index(&self.buffer, self.cursor);
 			if self.buffer.len() > cursor_byte_index && !self.buffer.is_empty() {
 				self.buffer.remove(cursor_byte_index);
+				self.update_censored_buffer();
 			}
 			self.scroll_cursor_into_view(c);
 		} else if rmar.is_key_pressed(keys::KEY_Home) {
@@ -172,37 +176,76 @@ impl Textbox {
 			self.scroll_cursor_into_view(c);
 		}
 		let input_characters = rmar.get_typed_characters();
-		if input_characters.len() > 0 {
-			let cursor_byte_index = Self::char_index_to_byte_index(&self.buffer, self.cursor);
-			self.buffer.insert_str(cursor_byte_index, &input_characters);
-			self.cursor += input_characters.len();
-			self.scroll_cursor_into_view(c);
+		self.insert_text(&input_characters, c);
+	}
+
+	/// Insert text at the cursor, as if it was typed.
+	pub fn insert_text(&mut self, text: &str, c: &mut clay_layout::Clay) {
+		if text.is_empty() || self.disabled {
+			return;
 		}
-		let buffer_chars_count = Self::chars_count(&self.buffer);
-		self.censored_buffer = "•".repeat(buffer_chars_count);
+		let cursor_byte_index = Self::char_index_to_byte_index(&self.buffer, self.cursor);
+		self.buffer.insert_str(cursor_byte_index, text);
+		self.cursor += Self::chars_count(text);
+		self.update_censored_buffer();
+		self.scroll_cursor_into_view(c);
+	}
+
+	/// Delete the character before the cursor.
+	pub fn backspace(&mut self, c: &mut clay_layout::Clay) {
+		if self.disabled {
+			return;
+		}
+		if self.cursor > 0 {
+			let cursor_byte_index = Self::char_index_to_byte_index(&self.buffer, self.cursor - 1);
+			self.cursor -= 1;
+			self.buffer.remove(cursor_byte_index);
+			self.update_censored_buffer();
+		}
+		self.scroll_cursor_into_view(c);
+	}
+
+	/// Empty the textbox, like after a wrong password.
+	pub fn clear(&mut self) {
+		self.buffer.clear();
+		self.cursor = 0;
+		self.update_censored_buffer();
+	}
+
+	fn update_censored_buffer(&mut self) {
+		self.censored_buffer = "•".repeat(Self::chars_count(&self.buffer));
 	}
 
 	pub fn render<'clay, 'render>(&'render self, c: &mut TibsClayScope<'clay, 'render>)
 	where
 		'clay: 'render,
 	{
+		let mut decl = Declaration::new();
+		decl
+			.layout()
+			.width(fixed!(300.0))
+			.height(fixed!(50.0))
+			.padding(Padding::all(15))
+			.child_alignment(Alignment::new(
+				clay_layout::layout::LayoutAlignmentX::Left,
+				clay_layout::layout::LayoutAlignmentY::Center,
+			))
+			.end()
+			.clip(true, false, c.scroll_offset())
+			.id(c.id(&self.id))
+			.background_color((0x0E, 0x1A, 0x26, 0x30).into())
+			.corner_radius()
+			.all(10.0)
+			.end();
+		if self.warning {
+			decl
+				.border()
+				.color((0xFF, 0xCC, 0x00, 0xA0).into())
+				.all_directions(1)
+				.end();
+		}
 		c.with(
-			Declaration::new()
-				.layout()
-				.width(fixed!(300.0))
-				.height(fixed!(50.0))
-				.padding(Padding::all(15))
-				.child_alignment(Alignment::new(
-					clay_layout::layout::LayoutAlignmentX::Left,
-					clay_layout::layout::LayoutAlignmentY::Center,
-				))
-				.end()
-				.clip(true, false, c.scroll_offset())
-				.id(c.id(&self.id))
-				.background_color((0x0E, 0x1A, 0x26, 0x30).into())
-				.corner_radius()
-				.all(10.0)
-				.end(),
+			&decl,
 			|c| {
 				let buffer_to_render = if self.hide_input {
 					&self.censored_buffer
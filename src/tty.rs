@@ -2,10 +2,31 @@ use nix::libc;
 use rustamarine::Rustamarine;
 use std::{
 	fs::{File, OpenOptions},
+	io,
 	mem::MaybeUninit,
 	os::fd::AsRawFd,
 };
 
+// From linux/vt.h and linux/kd.h
+const VT_OPENQRY: libc::c_ulong = 0x5600;
+const VT_SETMODE: libc::c_ulong = 0x5602;
+const VT_GETSTATE: libc::c_ulong = 0x5603;
+const VT_ACTIVATE: libc::c_ulong = 0x5606;
+const VT_WAITACTIVE: libc::c_ulong = 0x5607;
+const VT_AUTO: libc::c_char = 0x00;
+const KDSETMODE: libc::c_ulong = 0x4B3A;
+const KD_TEXT: libc::c_int = 0x00;
+
+#[repr(C)]
+#[derive(Debug, Default)]
+struct vt_mode {
+	mode: libc::c_char,
+	waitv: libc::c_char,
+	relsig: libc::c_short,
+	acqsig: libc::c_short,
+	frsig: libc::c_short,
+}
+
 pub struct TTYInfo {
 	pub fd: File,
 	pub number: u16,
@@ -19,12 +40,77 @@ impl TTYInfo {
 			.ok()
 			.map(|f| TTYInfo { fd: f, number: i })
 	}
-	pub fn make_current(&self, rmar: &mut Rustamarine) {
-		rmar.go_to_tty(self.number);
+
+	/// Open the first VT that no process has open, as reported by the kernel.
+	///
+	/// The VT stays reserved for as long as the returned [`TTYInfo`] lives.
+	pub fn allocate() -> io::Result<Self> {
+		let console = Self::open_console()?;
+		let mut number: libc::c_int = -1;
+		ioctl(
+			&console,
+			VT_OPENQRY,
+			&mut number as *mut libc::c_int as libc::c_ulong,
+		)?;
+		if number <= 0 {
+			return Err(io::Error::other("There are no free VTs left"));
+		}
+		Self::new(number as u16).ok_or_else(io::Error::last_os_error)
+	}
+
+	fn open_console() -> io::Result<File> {
+		OpenOptions::new()
+			.read(true)
+			.write(true)
+			.open("/dev/tty0")
+			.or_else(|_| File::open("/dev/console"))
+	}
+
+	/// Switch to this VT, blocking until the switch is done.
+	pub fn activate(&self) -> io::Result<()> {
+		let console = Self::open_console()?;
+		ioctl(&console, VT_ACTIVATE, self.number as libc::c_ulong)?;
+		ioctl(&console, VT_WAITACTIVE, self.number as libc::c_ulong)
+	}
+
+	/// Switch to this VT.
+	///
+	/// When rustamarine owns a seat the switch goes through it, so it can release
+	/// the display before the VT changes. Otherwise the VT is activated directly.
+	pub fn make_current(&self, rmar: &Rustamarine) -> io::Result<()> {
+		if rmar.is_drm() {
+			rmar.go_to_tty(self.number);
+			Ok(())
+		} else {
+			self.activate()
+		}
+	}
+
+	/// Let the kernel switch away from this VT immediately, instead of asking the process
+	/// that owns it first.
+	fn set_auto_mode(&self) -> io::Result<()> {
+		let mode = vt_mode {
+			mode: VT_AUTO,
+			..Default::default()
+		};
+		ioctl(
+			&self.fd,
+			VT_SETMODE,
+			&mode as *const vt_mode as libc::c_ulong,
+		)
+	}
+
+	/// Put a VT back into a state where the kernel can switch away from it freely.
+	///
+	/// A compositor that crashed while owning the VT can leave it in process switching
+	/// mode or in graphics mode, which would block VT switching or leave a blank screen.
+	/// Process switching on our own VT is handled by the seat backend in rustamarine.
+	pub fn reset(&self) -> io::Result<()> {
+		self.set_auto_mode()?;
+		ioctl(&self.fd, KDSETMODE, KD_TEXT as libc::c_ulong)
 	}
 
 	pub fn get_active_tty_number() -> u16 {
-		const VT_GETSTATE: libc::c_ulong = 0x5603;
 		#[repr(C)]
 		#[derive(Debug)]
 		struct vt_stat {
@@ -41,3 +127,10 @@ impl TTYInfo {
 		unsafe { vt.assume_init().v_active }
 	}
 }
+
+fn ioctl(file: &File, request: libc::c_ulong, arg: libc::c_ulong) -> io::Result<()> {
+	if unsafe { libc::ioctl(file.as_raw_fd(), request, arg) } < 0 {
+		return Err(io::Error::last_os_error());
+	}
+	Ok(())
+}
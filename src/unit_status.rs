@@ -0,0 +1,124 @@
+use nix::libc;
+use std::{io, process::Command};
+use zbus_systemd::systemd1::{ManagerProxy, MountProxy, ServiceProxy, SocketProxy, UnitProxy};
+use zbus_systemd::zbus::{self, Connection};
+
+use crate::session_supervisor::ExitReason;
+
+/// Why a unit failed, as far as systemd can tell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitStatus {
+	pub description: String,
+	/// Like `failed (exit-code)`
+	pub state: String,
+	/// `Result` of the unit, only services, mounts and sockets have one
+	pub result: Option<String>,
+	/// How the main process of a service ended, like `Main process exited with status 1`
+	pub main_process: Option<String>,
+}
+
+impl UnitStatus {
+	pub fn of_unit(unit: &str) -> zbus::Result<Self> {
+		smol::block_on(async {
+			let connection = Connection::system().await?;
+			let manager = ManagerProxy::new(&connection).await?;
+			let path = manager.get_unit(unit.to_string()).await?;
+			let proxy = UnitProxy::new(&connection, path.clone()).await?;
+			let state = format!(
+				"{} ({})",
+				proxy.active_state().await?,
+				proxy.sub_state().await?
+			);
+			let (result, main_process) = match unit.rsplit_once('.').map(|(_, kind)| kind) {
+				Some("service") => {
+					let service = ServiceProxy::new(&connection, path).await?;
+					let main_process = describe_main_process(
+						service.exec_main_code().await?,
+						service.exec_main_status().await?,
+					);
+					(Some(service.result().await?), main_process)
+				}
+				Some("mount") => (
+					Some(MountProxy::new(&connection, path).await?.result().await?),
+					None,
+				),
+				Some("socket") => (
+					Some(SocketProxy::new(&connection, path).await?.result().await?),
+					None,
+				),
+				_ => (None, None),
+			};
+			Ok(Self {
+				description: proxy.description().await?,
+				state,
+				result,
+				main_process,
+			})
+		})
+	}
+}
+
+/// The last lines a unit logged during this boot, read from the journal with `journalctl`.
+pub fn journal_tail(unit: &str, lines: usize) -> io::Result<Vec<String>> {
+	let output = Command::new("journalctl")
+		.args(["--boot", "--no-pager", "--quiet", "--output=short"])
+		.arg(format!("--lines={lines}"))
+		.arg(format!("--unit={unit}"))
+		.output()?;
+	if !output.status.success() {
+		return Err(io::Error::other(format!(
+			"journalctl failed: {}",
+			String::from_utf8_lossy(&output.stderr).trim()
+		)));
+	}
+	Ok(
+		String::from_utf8_lossy(&output.stdout)
+			.lines()
+			.map(str::to_string)
+			.collect(),
+	)
+}
+
+/// Queue a restart of the unit, replacing any job it already has.
+pub fn restart(unit: &str) -> zbus::Result<()> {
+	smol::block_on(async {
+		let connection = Connection::system().await?;
+		let manager = ManagerProxy::new(&connection).await?;
+		manager
+			.restart_unit(unit.to_string(), "replace".to_string())
+			.await?;
+		Ok(())
+	})
+}
+
+/// How the main process of a service ended, from its `ExecMainCode` (a `si_code`) and `ExecMainStatus`.
+fn describe_main_process(code: i32, status: i32) -> Option<String> {
+	match code {
+		libc::CLD_EXITED | libc::CLD_KILLED | libc::CLD_DUMPED => Some(format!(
+			"Main process {}",
+			ExitReason::from_child_code(code, status)
+		)),
+		// Never started, or still running
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_describe_main_process() {
+		assert_eq!(
+			describe_main_process(libc::CLD_EXITED, 1).as_deref(),
+			Some("Main process exited with code 1")
+		);
+		assert!(describe_main_process(libc::CLD_KILLED, libc::SIGKILL)
+			.unwrap()
+			.starts_with("Main process killed by signal 9 ("));
+		assert!(describe_main_process(libc::CLD_DUMPED, libc::SIGSEGV)
+			.unwrap()
+			.ends_with("(core dumped)"));
+		assert_eq!(describe_main_process(0, 0), None);
+	}
+}
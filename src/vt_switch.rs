@@ -0,0 +1,115 @@
+use rustamarine::{keys, Keysym, Modifiers, Rustamarine, Shortcut};
+
+const DEFAULT_MODIFIERS: Modifiers = Modifiers::CTRL.union(Modifiers::ALT);
+
+/// Switches to a text console with `Ctrl+Alt+F1` to `Ctrl+Alt+F12`.
+///
+/// The modifiers can be changed with `TIBS_VT_SWITCH_MODIFIERS` (e.g. `Super+Alt`),
+/// or set to `none` to disable VT switching.
+pub struct VtSwitcher {
+	shortcuts: Vec<(Shortcut, u16)>,
+}
+
+impl VtSwitcher {
+	pub fn from_env() -> Self {
+		let modifiers = match std::env::var("TIBS_VT_SWITCH_MODIFIERS") {
+			Ok(value) if value.trim().eq_ignore_ascii_case("none") => None,
+			Ok(value) => Some(parse_modifiers(&value).unwrap_or_else(|| {
+				log::warn!("Invalid TIBS_VT_SWITCH_MODIFIERS '{value}', using Ctrl+Alt");
+				DEFAULT_MODIFIERS
+			})),
+			Err(_) => Some(DEFAULT_MODIFIERS),
+		};
+		Self::new(modifiers)
+	}
+
+	pub fn new(modifiers: Option<Modifiers>) -> Self {
+		let shortcuts = modifiers
+			.map(|modifiers| {
+				(1..=12u16)
+					.map(|vt| {
+						let f_key = Keysym(keys::KEY_F1.raw() + vt as u32 - 1);
+						(Shortcut::new(modifiers, f_key), vt)
+					})
+					.collect()
+			})
+			.unwrap_or_default();
+		Self { shortcuts }
+	}
+
+	/// Get the VT the user asked to switch to this frame, if any.
+	pub fn requested_vt(&self, rmar: &Rustamarine) -> Option<u16> {
+		self.vt_for_keys(rmar.get_modifiers(), |keysym| rmar.is_key_pressed(keysym))
+	}
+
+	fn vt_for_keys(&self, modifiers: Modifiers, is_pressed: impl Fn(Keysym) -> bool) -> Option<u16> {
+		self
+			.shortcuts
+			.iter()
+			.find(|(shortcut, vt)| {
+				// Most keymaps turn Ctrl+Alt+Fn into XF86Switch_VT_n instead of Fn
+				let switch_vt_key = Keysym(keys::KEY_XF86Switch_VT_1.raw() + *vt as u32 - 1);
+				shortcut.matches_modifiers(modifiers)
+					&& (is_pressed(shortcut.keysym) || is_pressed(switch_vt_key))
+			})
+			.map(|(_, vt)| *vt)
+	}
+}
+
+fn parse_modifiers(value: &str) -> Option<Modifiers> {
+	Modifiers::from_names(value)
+		.ok()
+		.filter(|modifiers| !modifiers.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_modifiers() {
+		assert_eq!(
+			parse_modifiers("Super+Alt"),
+			Some(Modifiers::SUPER | Modifiers::ALT)
+		);
+		assert_eq!(parse_modifiers("ctrl"), Some(Modifiers::CTRL));
+		assert_eq!(parse_modifiers("Ctrl+F1"), None);
+		assert_eq!(parse_modifiers(" "), None);
+	}
+
+	#[test]
+	fn test_requested_vt() {
+		let switcher = VtSwitcher::new(Some(DEFAULT_MODIFIERS));
+		let ctrl_alt = Modifiers::CTRL | Modifiers::ALT;
+		assert_eq!(
+			switcher.vt_for_keys(ctrl_alt, |k| k == keys::KEY_F3),
+			Some(3)
+		);
+		assert_eq!(
+			switcher.vt_for_keys(ctrl_alt, |k| k == keys::KEY_F12),
+			Some(12)
+		);
+		assert_eq!(
+			switcher.vt_for_keys(ctrl_alt, |k| k == keys::KEY_XF86Switch_VT_5),
+			Some(5)
+		);
+		// Lock modifiers don't matter, other modifiers do
+		assert_eq!(
+			switcher.vt_for_keys(ctrl_alt | Modifiers::NUM_LOCK, |k| k == keys::KEY_F2),
+			Some(2)
+		);
+		assert_eq!(
+			switcher.vt_for_keys(Modifiers::CTRL, |k| k == keys::KEY_F2),
+			None
+		);
+		assert_eq!(
+			switcher.vt_for_keys(ctrl_alt | Modifiers::SHIFT, |k| k == keys::KEY_F2),
+			None
+		);
+		assert_eq!(switcher.vt_for_keys(ctrl_alt, |_| false), None);
+		assert_eq!(
+			VtSwitcher::new(None).vt_for_keys(ctrl_alt, |k| k == keys::KEY_F2),
+			None
+		);
+	}
+}